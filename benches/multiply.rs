@@ -0,0 +1,27 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rust_matrix::{Matrix, MatrixElement};
+
+fn square(size: usize) -> Matrix {
+    Matrix::from_fn(size, size, |i, j| {
+        MatrixElement::from(((i * 31 + j * 17) % 101) as f64 / 10.0 - 5.0)
+    })
+}
+
+fn multiply(c: &mut Criterion) {
+    let m1 = square(512);
+    let m2 = square(512);
+
+    let mut group = c.benchmark_group("multiply");
+    group.sample_size(10);
+    group.bench_function("512x512", |b| {
+        b.iter(|| black_box(&m1).multiply(black_box(&m2)).unwrap())
+    });
+    #[cfg(feature = "rayon")]
+    group.bench_function("512x512 parallel", |b| {
+        b.iter(|| black_box(&m1).multiply_parallel(black_box(&m2)).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, multiply);
+criterion_main!(benches);
@@ -1 +1,7 @@
+mod jacobi;
 mod lu;
+mod qr;
+mod schur;
+mod svd;
+
+pub use lu::LuFactors;
@@ -0,0 +1,219 @@
+use crate::{Error, Matrix, MatrixElement, Result, Vector};
+
+/// The maximum number of sweeps in the Jacobi eigenvalue algorithm.
+const MAX_SWEEPS: usize = 100;
+
+impl Matrix {
+    /// Returns the eigenvalues of a symmetric matrix computed with the cyclic Jacobi eigenvalue
+    /// algorithm.
+    ///
+    /// NOTE: The eigenvalues are the diagonal after convergence, so they are not sorted.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square or not symmetric.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let m = matrix![
+    ///     2, 1;
+    ///     1, 2;
+    /// ];
+    ///
+    /// let mut values = m.eigenvalues_symmetric().unwrap().data;
+    /// values.sort_by(|a, b| a.epsilon_cmp(b));
+    ///
+    /// assert!(Vector::new(values).epsilon_equals(&vector![1, 3]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Jacobi eigenvalue algorithm](https://en.wikipedia.org/wiki/Jacobi_eigenvalue_algorithm)
+    /// * [`Matrix::power_iteration`]
+    pub fn eigenvalues_symmetric(&self) -> Result<Vector> {
+        Ok(self._jacobi_eigen()?.0)
+    }
+
+    /// Diagonalizes a symmetric matrix with the Jacobi eigenvalue algorithm.
+    ///
+    /// Returns `(D, V)`, where `D` is the diagonal matrix of the eigenvalues and `V` is the
+    /// orthogonal matrix whose columns are the corresponding eigenvectors, so that `A = V * D * Vᵀ`.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square or not symmetric.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     2, 1;
+    ///     1, 2;
+    /// ];
+    ///
+    /// let (d, v) = m.diagonalize().unwrap();
+    ///
+    /// assert!((v.clone() * d * v.transpose()).epsilon_equals(&m));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Diagonalizable matrix](https://en.wikipedia.org/wiki/Diagonalizable_matrix)
+    /// * [`Matrix::eigenvalues_symmetric`]
+    /// * [`Matrix::similarity_transform`]
+    pub fn diagonalize(&self) -> Result<(Self, Self)> {
+        let (values, vectors) = self._jacobi_eigen()?;
+        let n = values.len();
+
+        let d = Self::from_fn(n, n, |i, j| {
+            if i == j {
+                values[i]
+            } else {
+                MatrixElement::zero()
+            }
+        });
+
+        Ok((d, vectors))
+    }
+
+    /// Returns the eigenvalues of a symmetric matrix and the orthogonal matrix whose columns are the
+    /// corresponding eigenvectors.
+    fn _jacobi_eigen(&self) -> Result<(Vector, Self)> {
+        self.assert_square("Only square matrices have eigenvalues")?;
+
+        if !self.is_symmetric() {
+            return Err(Error::InvalidOperation("The matrix must be symmetric"));
+        }
+
+        let n = self.rows_number;
+
+        let mut a = (0..n)
+            .map(|i| (0..n).map(|j| self[(i, j)].value()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let mut v = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| if i == j { 1.0 } else { 0.0 })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let total = a.iter().flatten().map(|x| x * x).sum::<f64>();
+
+        for _ in 0..MAX_SWEEPS {
+            let off_diagonal = (0..n)
+                .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+                .map(|(i, j)| a[i][j] * a[i][j])
+                .sum::<f64>();
+
+            if off_diagonal <= f64::EPSILON * f64::EPSILON * total {
+                break;
+            }
+
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    if a[p][q] == 0.0 {
+                        continue;
+                    }
+
+                    // the rotation J with J[p, p] = J[q, q] = c and J[p, q] = -J[q, p] = s
+                    // makes (Jᵀ * A * J)[p, q] zero
+                    let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+
+                    let rotate = |x: f64, y: f64| (c * x - s * y, s * x + c * y);
+
+                    for row in a.iter_mut().chain(v.iter_mut()) {
+                        (row[p], row[q]) = rotate(row[p], row[q]);
+                    }
+                    let (top, bottom) = a.split_at_mut(q);
+                    for (x, y) in top[p].iter_mut().zip(bottom[0].iter_mut()) {
+                        (*x, *y) = rotate(*x, *y);
+                    }
+                }
+            }
+        }
+
+        Ok((
+            (0..n).map(|i| MatrixElement::new(a[i][i])).collect(),
+            Self::from_fn(n, n, |i, j| MatrixElement::new(v[i][j])),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, vector};
+
+    fn sorted(vector: Vector) -> Vector {
+        let mut data = vector.data;
+        data.sort_by(|a, b| a.epsilon_cmp(b));
+
+        data.into()
+    }
+
+    #[test]
+    fn eigenvalues_symmetric() {
+        // R * diag(2, 5) * Rᵀ where R is a rotation
+        let (s, c) = 0.3_f64.sin_cos();
+        let r = matrix![
+            c, -s;
+            s, c;
+        ];
+        let m = r.clone() * matrix![2, 0; 0, 5] * r.transpose();
+
+        assert!(sorted(m.eigenvalues_symmetric().unwrap()).epsilon_equals(&vector![2, 5]));
+
+        let m = matrix![
+            2, -1, 0;
+            -1, 2, -1;
+            0, -1, 2;
+        ];
+        let sqrt2 = 2.0_f64.sqrt();
+
+        assert!(
+            sorted(m.eigenvalues_symmetric().unwrap()).epsilon_equals(&vector![
+                2.0 - sqrt2,
+                2,
+                2.0 + sqrt2
+            ])
+        );
+    }
+
+    #[test]
+    fn diagonalize() {
+        let m = matrix![
+            4, 1, -2;
+            1, 3, 0;
+            -2, 0, 5;
+        ];
+
+        let (d, v) = m.diagonalize().unwrap();
+
+        assert!(v.is_orthogonal());
+        assert!((v.clone() * d.clone() * v.transpose()).epsilon_equals(&m));
+        assert!(m.similarity_transform(&v).unwrap().epsilon_equals(&d));
+
+        for i in 0..3 {
+            for j in (0..3).filter(|&j| j != i) {
+                assert!(d.get(i, j).unwrap().is_zero());
+            }
+        }
+
+        matrix![1, 2; 3, 4].diagonalize().unwrap_err();
+    }
+
+    #[test]
+    fn eigenvalues_symmetric_invalid() {
+        matrix![1, 2; 3, 4].eigenvalues_symmetric().unwrap_err();
+        matrix![1, 2, 3; 2, 4, 5]
+            .eigenvalues_symmetric()
+            .unwrap_err();
+    }
+}
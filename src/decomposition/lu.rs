@@ -1,4 +1,4 @@
-use crate::{Matrix, Result};
+use crate::{Error, Matrix, MatrixElement, Result, Vector};
 
 impl Matrix {
     /// Returns the LU decomposition of the matrix.
@@ -32,10 +32,9 @@ impl Matrix {
         let m = self.rows_number;
         let n = self.cols_number;
 
-        let p = self._pivot()?;
-
+        let mut p = Matrix::identity(m);
         let mut l = Matrix::identity(m);
-        let mut u = p.clone() * self.clone();
+        let mut u = self.clone();
 
         let mut i = 0;
         let mut j = 0;
@@ -60,10 +59,26 @@ impl Matrix {
         //  Thus, L = [ -2  1  0  0  ]
         //            [  1 -3  1  0  ]
         //            [ -3  4  2  1  ]
+        //
+        // The example above ignores row swaps for clarity. In fact, the row with the largest
+        // absolute value in the current column is swapped to the pivot position first, and the
+        // multipliers already stored in `L` are swapped along with it.
         while i < m && j < n {
-            let pivot = u.get(i, j)?;
+            // find the element with the largest absolute value in the current column
+            let max_index = u.argmax_abs_in_column(j, i)?;
+
+            let pivot = u.get(max_index, j)?;
 
             if !pivot.is_zero() {
+                if max_index != i {
+                    u.swap_rows(i, max_index)?;
+                    p.swap_rows(i, max_index)?;
+
+                    for k in 0..i {
+                        l.swap((i, k), (max_index, k))?;
+                    }
+                }
+
                 for k in (i + 1)..m {
                     let factor = u.get(k, j)? / pivot;
 
@@ -82,44 +97,109 @@ impl Matrix {
         Ok((l, u, p))
     }
 
-    /// Returns the permutation matrix `P`.
-    fn _pivot(&self) -> Result<Self> {
-        let m = self.rows_number;
-        let n = self.cols_number;
-
-        let mut p = Matrix::identity(m);
+    /// Returns the LU factorization of a square matrix, which can be reused to solve many systems.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square or cannot be inverted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let m = matrix![
+    ///     2, 1, 1;
+    ///     4, -6, 0;
+    ///     -2, 7, 2;
+    /// ];
+    ///
+    /// let lu = m.lu_factor().unwrap();
+    ///
+    /// assert!(lu.solve(&vector![5, -2, 9]).unwrap().epsilon_equals(&vector![1, 1, 2]));
+    /// assert!(lu.solve(&vector![4, -2, 5]).unwrap().epsilon_equals(&m.solve(&vector![4, -2, 5]).unwrap()));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::lup_decomposition`]
+    /// * [`Matrix::solve`]
+    pub fn lu_factor(&self) -> Result<LuFactors> {
+        self.assert_square("Only square matrices can be factored for solving")?;
 
-        let mut i = 0;
-        let mut j = 0;
+        let (l, u, p) = self.lup_decomposition()?;
 
-        while i < m && j < n {
-            let mut max_index = i;
-            for k in i..m {
-                if self
-                    .get(k, j)?
-                    .abs()
-                    .epsilon_gt(&self.get(max_index, j)?.abs())
-                {
-                    max_index = k;
-                }
+        for i in 0..u.rows_number {
+            if u.get(i, i)?.is_zero() {
+                return Err(Error::InvalidOperation("The matrix cannot be inverted"));
             }
+        }
+
+        Ok(LuFactors { l, u, p })
+    }
+}
+
+/// The LU factorization of a square matrix, such that `P * A == L * U`.
+///
+/// # See also
+///
+/// * [`Matrix::lu_factor`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LuFactors {
+    /// The unit lower triangular matrix.
+    pub l: Matrix,
+
+    /// The upper triangular matrix.
+    pub u: Matrix,
+
+    /// The permutation matrix.
+    pub p: Matrix,
+}
+
+impl LuFactors {
+    /// Solves `A * x == b` by forward and back substitution.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the length of `b` is different from the size of the matrix.
+    pub fn solve(&self, b: &Vector) -> Result<Vector> {
+        let n = self.u.rows_number;
 
-            if max_index != i {
-                p.swap_rows(i, max_index)?;
+        if b.len() != n {
+            return Err(Error::InvalidOperation(
+                "Vector length must be equal to matrix rows to solve",
+            ));
+        }
+
+        let b = self.p.multiply_vector(b)?;
+
+        // L * y = P * b
+        let mut y = Vec::with_capacity(n);
+        for i in 0..n {
+            let mut sum = b[i];
+            for (k, y_k) in y.iter().enumerate() {
+                sum -= self.l.get(i, k)? * *y_k;
             }
+            y.push(sum);
+        }
 
-            i += 1;
-            j += 1;
+        // U * x = y
+        let mut x = vec![MatrixElement::zero(); n];
+        for i in (0..n).rev() {
+            let mut sum = y[i];
+            for (k, x_k) in x.iter().enumerate().skip(i + 1) {
+                sum -= self.u.get(i, k)? * *x_k;
+            }
+            x[i] = sum / self.u.get(i, i)?;
         }
 
-        Ok(p)
+        Ok(x.into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{matrix, MatrixElement};
+    use crate::{matrix, vector, MatrixElement};
 
     #[test]
     fn lu() {
@@ -147,5 +227,49 @@ mod tests {
         // panic!();
 
         assert!((p * m).epsilon_equals(&(l * u)));
+
+        // a zero pivot appears only after elimination
+        let m = matrix![
+            1, 1, 0;
+            1, 1, 1;
+            0, 1, 1;
+        ];
+        let (l, u, p) = m.lup_decomposition().unwrap();
+        assert!((p * m).epsilon_equals(&(l * u.clone())));
+        assert!(u.get(2, 0).unwrap().is_zero());
+        assert!(u.get(2, 1).unwrap().is_zero());
+        assert!(u.get(1, 0).unwrap().is_zero());
+    }
+
+    #[test]
+    fn lu_factor_solve() {
+        let m = matrix![
+            4, -2, 1, 3;
+            3, 6, -4, 2;
+            2, 1, 8, -5;
+            1, 1, 1, 9;
+        ];
+        let lu = m.lu_factor().unwrap();
+
+        for b in [
+            vector![1, 2, 3, 4],
+            vector![-3, 0, 7.5, 1],
+            vector![0, 0, 0, 1],
+        ] {
+            assert!(lu.solve(&b).unwrap().epsilon_equals(&m.solve(&b).unwrap()));
+        }
+
+        lu.solve(&vector![1, 2, 3]).unwrap_err();
+    }
+
+    #[test]
+    fn lu_factor_singular() {
+        let m = matrix![
+            1, 3, 4;
+            2, 5, 6;
+            3, 7, 8;
+        ];
+
+        m.lu_factor().unwrap_err();
     }
 }
@@ -0,0 +1,227 @@
+use crate::{Error, Matrix, MatrixElement, Result, Vector};
+
+impl Matrix {
+    /// Returns the QR decomposition of the matrix computed with the modified Gram-Schmidt process.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing `Q`, whose columns are orthonormal, and the upper triangular square
+    /// matrix `R`. Thus, self == Q * R.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix has more columns than rows, or if its columns are linearly
+    /// dependent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     3, 1;
+    ///     4, 2;
+    ///     0, 2;
+    /// ];
+    ///
+    /// let (q, r) = m.qr_decomposition().unwrap();
+    ///
+    /// assert!((q.transpose() * q.clone()).epsilon_equals(&Matrix::identity(2)));
+    /// assert!((q * r).epsilon_equals(&m));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Gram–Schmidt process](https://en.wikipedia.org/wiki/Gram%E2%80%93Schmidt_process)
+    /// * [`Matrix::householder_qr`]
+    pub fn qr_decomposition(&self) -> Result<(Self, Self)> {
+        let n = self.cols_number;
+
+        if self.rows_number < n {
+            return Err(Error::InvalidOperation(
+                "QR decomposition by Gram-Schmidt requires at least as many rows as columns",
+            ));
+        }
+
+        let mut q: Vec<Vector> = Vec::with_capacity(n);
+        let mut r = Matrix::zero(n, n);
+
+        for (j, mut v) in self.as_cols().into_iter().enumerate() {
+            for (i, q_i) in q.iter().enumerate() {
+                let r_ij = q_i.dot(&v);
+
+                r.set(i, j, r_ij)?;
+                v = v.subtract(&q_i.scale(r_ij));
+            }
+
+            let r_jj = v.norm();
+            if r_jj.is_zero() {
+                return Err(Error::InvalidOperation(
+                    "QR decomposition by Gram-Schmidt requires linearly independent columns",
+                ));
+            }
+
+            r.set(j, j, r_jj)?;
+            q.push(v.scale(r_jj.inverse()));
+        }
+
+        Ok((Self::from_cols(q), r))
+    }
+
+    /// Returns the QR decomposition of the matrix computed with Householder reflections.
+    ///
+    /// It is numerically more stable than [`Matrix::qr_decomposition`] and works for matrices of
+    /// any shape.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the orthogonal square matrix `Q` and the upper triangular matrix `R`,
+    /// which has the same size as the original matrix. Thus, self == Q * R.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     12, -51, 4;
+    ///     6, 167, -68;
+    ///     -4, 24, -41;
+    /// ];
+    ///
+    /// let (q, r) = m.householder_qr().unwrap();
+    ///
+    /// assert!((q.transpose() * q.clone()).epsilon_equals(&Matrix::identity(3)));
+    /// assert!((q * r).epsilon_equals(&m));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [QR decomposition using Householder reflections](https://en.wikipedia.org/wiki/QR_decomposition#Using_Householder_reflections)
+    /// * [`Matrix::qr_decomposition`]
+    pub fn householder_qr(&self) -> Result<(Self, Self)> {
+        let m = self.rows_number;
+        let n = self.cols_number;
+
+        let mut r = (0..m)
+            .map(|i| (0..n).map(|j| self[(i, j)].value()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let mut q = (0..m)
+            .map(|i| {
+                (0..m)
+                    .map(|j| if i == j { 1.0 } else { 0.0 })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        for k in 0..n.min(m.saturating_sub(1)) {
+            // the reflector v maps x = R[k.., k] onto alpha * e₁
+            let x_norm = (k..m).map(|i| r[i][k] * r[i][k]).sum::<f64>().sqrt();
+            if x_norm == 0.0 {
+                continue;
+            }
+
+            let alpha = if r[k][k] > 0.0 { -x_norm } else { x_norm };
+
+            let mut v = (k..m).map(|i| r[i][k]).collect::<Vec<_>>();
+            v[0] -= alpha;
+
+            let v_norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+            v.iter_mut().for_each(|x| *x /= v_norm);
+
+            // R = H * R, where H = I - 2 * v * vᵀ acts on rows k..m
+            let dots = (0..n)
+                .map(|j| (k..m).map(|i| v[i - k] * r[i][j]).sum::<f64>())
+                .collect::<Vec<_>>();
+
+            for (i, row) in r.iter_mut().enumerate().skip(k) {
+                for (x, dot) in row.iter_mut().zip(&dots) {
+                    *x -= 2.0 * v[i - k] * dot;
+                }
+            }
+
+            // Q = Q * H, which acts on columns k..m
+            for row in q.iter_mut() {
+                let dot = (k..m).map(|j| row[j] * v[j - k]).sum::<f64>();
+
+                for j in k..m {
+                    row[j] -= 2.0 * dot * v[j - k];
+                }
+            }
+
+            for row in r.iter_mut().skip(k + 1) {
+                row[k] = 0.0;
+            }
+        }
+
+        Ok((
+            Self::from_fn(m, m, |i, j| MatrixElement::new(q[i][j])),
+            Self::from_fn(m, n, |i, j| MatrixElement::new(r[i][j])),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn qr_decomposition() {
+        let m = matrix![
+            1, -1, 4;
+            1, 4, -2;
+            1, 4, 2;
+            1, -1, 0;
+        ];
+        let (q, r) = m.qr_decomposition().unwrap();
+
+        assert!((q.transpose() * q.clone()).epsilon_equals(&Matrix::identity(3)));
+        assert!((q * r).epsilon_equals(&m));
+
+        matrix![1, 2, 3; 4, 5, 6].qr_decomposition().unwrap_err();
+        matrix![1, 2; 2, 4; 3, 6].qr_decomposition().unwrap_err();
+    }
+
+    #[test]
+    fn householder_qr() {
+        let m = matrix![
+            1, -1, 4;
+            1, 4, -2;
+            1, 4, 2;
+            1, -1, 0;
+        ];
+        let (q, r) = m.householder_qr().unwrap();
+
+        assert_eq!((q.rows_number, q.cols_number), (4, 4));
+        assert_eq!((r.rows_number, r.cols_number), (4, 3));
+        assert!((q.transpose() * q.clone()).epsilon_equals(&Matrix::identity(4)));
+        assert!((q * r.clone()).epsilon_equals(&m));
+
+        for i in 0..r.rows_number {
+            for j in 0..i.min(r.cols_number) {
+                assert!(r.get(i, j).unwrap().is_zero());
+            }
+        }
+
+        // R is unique up to the signs of its rows
+        let (_, gram_schmidt_r) = m.qr_decomposition().unwrap();
+        for i in 0..3 {
+            let row = r.get_row(i).unwrap();
+            let expected = gram_schmidt_r.get_row(i).unwrap();
+
+            assert!(row.epsilon_equals(&expected) || row.epsilon_equals(&expected.negate()));
+        }
+    }
+
+    #[test]
+    fn householder_qr_wide() {
+        let m = matrix![
+            2, -1, 0, 3;
+            1, 5, -2, 1;
+        ];
+        let (q, r) = m.householder_qr().unwrap();
+
+        assert!((q.transpose() * q.clone()).epsilon_equals(&Matrix::identity(2)));
+        assert!((q * r.clone()).epsilon_equals(&m));
+        assert!(r.get(1, 0).unwrap().is_zero());
+    }
+}
@@ -0,0 +1,362 @@
+use crate::{Error, Matrix, MatrixElement, Result};
+
+/// The maximum number of QR steps spent on each eigenvalue before giving up.
+const MAX_ITERATIONS_PER_EIGENVALUE: usize = 30;
+
+/// The number of QR steps on the same block after which an exceptional shift is used.
+const EXCEPTIONAL_SHIFT_PERIOD: usize = 10;
+
+impl Matrix {
+    /// Returns the real Schur form of the matrix, computed with the Francis double shift QR
+    /// algorithm.
+    ///
+    /// The matrix is first reduced to upper Hessenberg form, then QR steps with implicit double
+    /// shifts are applied to the unreduced trailing block, which is deflated as soon as one of its
+    /// subdiagonal elements becomes negligible.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the quasi-upper-triangular matrix `T` and the orthogonal matrix `Q`, so
+    /// that self == Q * T * Qᵀ. Every real eigenvalue appears on the diagonal of `T`, and every
+    /// pair of complex conjugate eigenvalues is kept as a `2 × 2` block on its diagonal.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square, or if the iteration does not converge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     2, 1;
+    ///     1, 2;
+    /// ];
+    ///
+    /// let (t, q) = m.schur_form().unwrap();
+    ///
+    /// assert!(t.get(1, 0).unwrap().is_zero());
+    /// assert!((q.clone() * t * q.transpose()).epsilon_equals(&m));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Schur decomposition](https://en.wikipedia.org/wiki/Schur_decomposition)
+    /// * Wikipedia: [QR algorithm](https://en.wikipedia.org/wiki/QR_algorithm)
+    /// * Wikipedia: [Hessenberg matrix](https://en.wikipedia.org/wiki/Hessenberg_matrix)
+    /// * [`Matrix::is_upper_hessenberg`]
+    pub fn schur_form(&self) -> Result<(Self, Self)> {
+        self.assert_square("Only square matrices have Schur forms")?;
+
+        let n = self.rows_number;
+
+        let mut t: Vec<f64> = self.elements.iter().map(|x| x.value()).collect();
+        let mut q: Vec<f64> = (0..n * n)
+            .map(|k| if k / n == k % n { 1.0 } else { 0.0 })
+            .collect();
+
+        _reduce_to_hessenberg(&mut t, &mut q, n);
+
+        let norm = t.iter().map(|x| x.abs()).fold(0.0, f64::max);
+
+        let mut hi = n.saturating_sub(1);
+        let mut iterations = 0;
+        let mut total_iterations = 0;
+
+        while hi > 0 {
+            // find the start of the unreduced block which ends at `hi`
+            let mut lo = hi;
+            while lo > 0 {
+                let scale = t[(lo - 1) * n + lo - 1].abs() + t[lo * n + lo].abs();
+                let scale = if scale == 0.0 { norm } else { scale };
+
+                if t[lo * n + lo - 1].abs() <= f64::EPSILON * scale {
+                    t[lo * n + lo - 1] = 0.0;
+                    break;
+                }
+                lo -= 1;
+            }
+
+            if lo == hi {
+                hi -= 1;
+                iterations = 0;
+            } else if lo + 1 == hi {
+                _split_real_pair_block(&mut t, &mut q, n, lo);
+                hi = hi.saturating_sub(2);
+                iterations = 0;
+            } else {
+                if total_iterations >= MAX_ITERATIONS_PER_EIGENVALUE * n {
+                    return Err(Error::InvalidOperation("The QR algorithm did not converge"));
+                }
+
+                iterations += 1;
+                total_iterations += 1;
+
+                let exceptional = iterations % EXCEPTIONAL_SHIFT_PERIOD == 0;
+                _francis_step(&mut t, &mut q, n, lo, hi, exceptional);
+            }
+        }
+
+        // clear the rounding errors below the subdiagonal
+        for i in 2..n {
+            for j in 0..i - 1 {
+                t[i * n + j] = 0.0;
+            }
+        }
+
+        Ok((
+            Self::from_fn(n, n, |i, j| MatrixElement::new(t[i * n + j])),
+            Self::from_fn(n, n, |i, j| MatrixElement::new(q[i * n + j])),
+        ))
+    }
+}
+
+/// Applies the reflection `I - beta * v * vᵀ` on the given indices to `a` from both sides, and to
+/// `q` from the right.
+fn _reflect(a: &mut [f64], q: &mut [f64], n: usize, indices: &[usize], v: &[f64], beta: f64) {
+    for j in 0..n {
+        let s: f64 = indices
+            .iter()
+            .zip(v)
+            .map(|(&i, vi)| vi * a[i * n + j])
+            .sum();
+        for (&i, vi) in indices.iter().zip(v) {
+            a[i * n + j] -= beta * s * vi;
+        }
+    }
+
+    for m in [a, q] {
+        for i in 0..n {
+            let s: f64 = indices
+                .iter()
+                .zip(v)
+                .map(|(&j, vj)| m[i * n + j] * vj)
+                .sum();
+            for (&j, vj) in indices.iter().zip(v) {
+                m[i * n + j] -= beta * s * vj;
+            }
+        }
+    }
+}
+
+/// Returns the Householder vector and its factor `beta` which map `x` onto a multiple of the first
+/// unit vector, or `None` if `x` is zero.
+fn _householder(x: &[f64]) -> Option<(Vec<f64>, f64)> {
+    let norm = x.iter().map(|xi| xi * xi).sum::<f64>().sqrt();
+    if norm == 0.0 {
+        return None;
+    }
+
+    let mut v = x.to_vec();
+    v[0] += if x[0] < 0.0 { -norm } else { norm };
+
+    Some((v.clone(), 2.0 / v.iter().map(|vi| vi * vi).sum::<f64>()))
+}
+
+/// Reduces `a` to upper Hessenberg form with Householder reflections, accumulated into `q`.
+fn _reduce_to_hessenberg(a: &mut [f64], q: &mut [f64], n: usize) {
+    for k in 0..n.saturating_sub(2) {
+        let x: Vec<f64> = (k + 1..n).map(|i| a[i * n + k]).collect();
+
+        if let Some((v, beta)) = _householder(&x) {
+            let indices: Vec<usize> = (k + 1..n).collect();
+            _reflect(a, q, n, &indices, &v, beta);
+        }
+
+        for i in k + 2..n {
+            a[i * n + k] = 0.0;
+        }
+    }
+}
+
+/// Performs one implicit double shift QR step on the unreduced Hessenberg block `lo..=hi`, whose
+/// shifts are the eigenvalues of its trailing `2 × 2` block, or ad hoc ones if `exceptional`.
+fn _francis_step(a: &mut [f64], q: &mut [f64], n: usize, lo: usize, hi: usize, exceptional: bool) {
+    let at = |a: &[f64], i: usize, j: usize| a[i * n + j];
+
+    let (s, t) = if exceptional {
+        let w = at(a, hi, hi - 1).abs() + at(a, hi - 1, hi - 2).abs();
+        (1.5 * w, w * w)
+    } else {
+        (
+            at(a, hi - 1, hi - 1) + at(a, hi, hi),
+            at(a, hi - 1, hi - 1) * at(a, hi, hi) - at(a, hi - 1, hi) * at(a, hi, hi - 1),
+        )
+    };
+
+    // the first column of (A - σ₁I)(A - σ₂I), which has only three nonzero elements
+    let mut x = at(a, lo, lo) * at(a, lo, lo) + at(a, lo, lo + 1) * at(a, lo + 1, lo)
+        - s * at(a, lo, lo)
+        + t;
+    let mut y = at(a, lo + 1, lo) * (at(a, lo, lo) + at(a, lo + 1, lo + 1) - s);
+    let mut z = at(a, lo + 1, lo) * at(a, lo + 2, lo + 1);
+
+    // chase the bulge down the subdiagonal
+    for k in lo..hi - 1 {
+        if let Some((v, beta)) = _householder(&[x, y, z]) {
+            _reflect(a, q, n, &[k, k + 1, k + 2], &v, beta);
+        }
+
+        // the reflection has moved the bulge out of the previous column
+        if k > lo {
+            a[(k + 1) * n + k - 1] = 0.0;
+            a[(k + 2) * n + k - 1] = 0.0;
+        }
+
+        x = at(a, k + 1, k);
+        y = at(a, k + 2, k);
+        if k + 3 <= hi {
+            z = at(a, k + 3, k);
+        }
+    }
+
+    if let Some((v, beta)) = _householder(&[x, y]) {
+        _reflect(a, q, n, &[hi - 1, hi], &v, beta);
+    }
+
+    a[hi * n + hi - 2] = 0.0;
+}
+
+/// Rotates the deflated `2 × 2` block starting at `k` into upper triangular form if its
+/// eigenvalues are real, and leaves it unchanged otherwise.
+fn _split_real_pair_block(a: &mut [f64], q: &mut [f64], n: usize, k: usize) {
+    let (p, r) = (a[k * n + k], a[k * n + k + 1]);
+    let (s, u) = (a[(k + 1) * n + k], a[(k + 1) * n + k + 1]);
+
+    let half_gap = (p - u) / 2.0;
+    let discriminant = half_gap * half_gap + r * s;
+    if discriminant < 0.0 {
+        return;
+    }
+
+    let lambda = (p + u) / 2.0 + discriminant.sqrt().copysign(half_gap);
+
+    // an eigenvector of the block, taken from the row which gives the larger one
+    let (x, y) = if (lambda - u).abs() + s.abs() >= r.abs() + (lambda - p).abs() {
+        (lambda - u, s)
+    } else {
+        (r, lambda - p)
+    };
+    let norm = x.hypot(y);
+    if norm == 0.0 {
+        return;
+    }
+
+    // the reflection which maps the first unit vector onto the eigenvector
+    let v = [1.0 - x / norm, -y / norm];
+    let beta_denominator = v[0] * v[0] + v[1] * v[1];
+    if beta_denominator != 0.0 {
+        _reflect(a, q, n, &[k, k + 1], &v, 2.0 / beta_denominator);
+    }
+
+    a[(k + 1) * n + k] = 0.0;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, Matrix, MatrixElement, Vector};
+
+    #[test]
+    fn schur_form_symmetric() {
+        let m = matrix![
+            4, 1, -2;
+            1, 3, 0;
+            -2, 0, 5;
+        ];
+
+        let (t, q) = m.schur_form().unwrap();
+
+        assert!(q.is_orthogonal());
+        assert!((q.clone() * t.clone() * q.transpose()).epsilon_equals(&m));
+        assert!(t
+            .diagonal()
+            .sorted()
+            .epsilon_equals(&m.eigenvalues_symmetric().unwrap().sorted()));
+
+        for i in 1..3 {
+            for j in 0..i {
+                assert!(t.get(i, j).unwrap().is_zero());
+            }
+        }
+    }
+
+    #[test]
+    fn schur_form_general() {
+        // eigenvalues 1, 2 and 3
+        let m = matrix![
+            2, 0, 1;
+            1, 1, 1;
+            0, 0, 3;
+        ];
+
+        let (t, q) = m.schur_form().unwrap();
+
+        assert!((q.clone() * t.clone() * q.transpose()).epsilon_equals(&m));
+        assert!(t.diagonal().sorted().epsilon_equals(&Vector::new(vec![
+            1.into(),
+            2.into(),
+            3.into()
+        ])));
+
+        matrix![1, 2, 3].schur_form().unwrap_err();
+    }
+
+    #[test]
+    fn schur_form_opposite_eigenvalues() {
+        for m in [
+            matrix![
+                0, 1;
+                1, 0;
+            ],
+            matrix![
+                2, 0, 0;
+                0, 0, 3;
+                0, 3, 0;
+            ],
+            matrix![
+                0, 0, 1;
+                0, 1, 0;
+                1, 0, 0;
+            ],
+        ] {
+            let (t, q) = m.schur_form().unwrap();
+
+            assert!(q.is_orthogonal());
+            assert!((q.clone() * t.clone() * q.transpose()).epsilon_equals(&m));
+            assert!(t
+                .diagonal()
+                .sorted()
+                .epsilon_equals(&m.eigenvalues_symmetric().unwrap().sorted()));
+
+            for i in 1..m.rows_number {
+                for j in 0..i {
+                    assert!(t.get(i, j).unwrap().is_zero());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn schur_form_complex_eigenvalues() {
+        // a cyclic permutation, whose eigenvalues are the cube roots of unity
+        let m = matrix![
+            0, 0, 1;
+            1, 0, 0;
+            0, 1, 0;
+        ];
+
+        let (t, q) = m.schur_form().unwrap();
+
+        assert!(q.is_orthogonal());
+        assert!((q.clone() * t.clone() * q.transpose()).epsilon_equals(&m));
+        assert!(t.get(2, 0).unwrap().is_zero());
+
+        // one real eigenvalue 1 and a 2 × 2 block holding the complex pair
+        let blocks = (1..3)
+            .filter(|&i| !t.get(i, i - 1).unwrap().is_zero())
+            .count();
+        assert_eq!(blocks, 1);
+        assert!(t.trace().unwrap().is_zero());
+        assert!(t.det().unwrap().epsilon_equals(&MatrixElement::one()));
+    }
+}
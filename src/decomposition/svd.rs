@@ -0,0 +1,119 @@
+use crate::{Matrix, MatrixElement, Vector};
+
+/// The maximum number of sweeps in [`Matrix::singular_values`].
+const MAX_SWEEPS: usize = 100;
+
+impl Matrix {
+    /// Returns the singular values of the matrix in descending order.
+    ///
+    /// There are `min(rows, cols)` singular values, computed with the one-sided Jacobi method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let m = matrix![
+    ///     3, 0;
+    ///     0, -4;
+    ///     0, 0;
+    /// ];
+    ///
+    /// assert!(m.singular_values().epsilon_equals(&vector![4, 3]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Singular value](https://en.wikipedia.org/wiki/Singular_value)
+    /// * Wikipedia: [One-sided Jacobi algorithm](https://en.wikipedia.org/wiki/Jacobi_eigenvalue_algorithm#Singular_values)
+    pub fn singular_values(&self) -> Vector {
+        // the singular values of A and Aᵀ are the same, so make sure that there are fewer columns
+        let mut cols = if self.rows_number < self.cols_number {
+            self.as_rows()
+        } else {
+            self.as_cols()
+        }
+        .into_iter()
+        .map(|col| col.into_iter().map(|x| x.value()).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+        let n = cols.len();
+
+        let dot = |a: &[f64], b: &[f64]| a.iter().zip(b).map(|(x, y)| x * y).sum::<f64>();
+
+        // orthogonalize the columns pair by pair until they are all orthogonal to each other
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    let alpha = dot(&cols[p], &cols[p]);
+                    let beta = dot(&cols[q], &cols[q]);
+                    let gamma = dot(&cols[p], &cols[q]);
+
+                    if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                        continue;
+                    }
+
+                    rotated = true;
+
+                    let zeta = (beta - alpha) / (2.0 * gamma);
+                    let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                    let c = 1.0 / (1.0 + t * t).sqrt();
+                    let s = c * t;
+
+                    for k in 0..cols[p].len() {
+                        let (x, y) = (cols[p][k], cols[q][k]);
+
+                        cols[p][k] = c * x - s * y;
+                        cols[q][k] = s * x + c * y;
+                    }
+                }
+            }
+
+            if !rotated {
+                break;
+            }
+        }
+
+        let mut values = cols
+            .iter()
+            .map(|col| MatrixElement::new(dot(col, col).sqrt()))
+            .collect::<Vec<_>>();
+        values.sort_by(|a, b| b.epsilon_cmp(a));
+
+        values.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, vector};
+
+    #[test]
+    fn singular_values() {
+        let m = matrix![
+            3, 2, 2;
+            2, 3, -2;
+        ];
+        assert!(m.singular_values().epsilon_equals(&vector![5, 3]));
+        assert!(m
+            .transpose()
+            .singular_values()
+            .epsilon_equals(&vector![5, 3]));
+
+        // the product of the singular values of a square matrix is |det|
+        let m = matrix![
+            4, -2, 1;
+            3, 6, -4;
+            2, 1, 8;
+        ];
+        let product = m
+            .singular_values()
+            .into_iter()
+            .fold(MatrixElement::one(), |acc, x| acc * x);
+        assert!(product.epsilon_equals(&m.det().unwrap().abs()));
+
+        let values = matrix![1, 2; 2, 4].singular_values();
+        assert!(values[1].is_zero());
+    }
+}
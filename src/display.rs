@@ -8,33 +8,99 @@ struct MatrixElementDisplay {
     str: String,
 }
 
+/// The number of fractional digits when the formatter has no precision.
+const DEFAULT_PRECISION: usize = 14;
+
 impl MatrixElementDisplay {
-    fn new(ele: MatrixElement) -> Self {
-        Self {
-            is_negative: ele.is_negative(),
-            str: if ele.is_integer() {
-                format!("{}", ele).trim_start_matches('-').to_string()
-            } else {
-                format!("{:.14}", ele)
-                    .trim_start_matches('-')
+    fn new(ele: MatrixElement, precision: usize) -> Self {
+        let str = if ele.is_integer() {
+            format!("{}", ele).trim_start_matches('-').to_string()
+        } else {
+            let formatted = format!("{:.precision$}", ele);
+            let unsigned = formatted.trim_start_matches('-');
+
+            // only fractional digits can be trimmed, which do not exist with a precision of 0
+            if unsigned.contains('.') {
+                unsigned
                     .trim_end_matches('0')
                     .trim_end_matches('.')
                     .to_string()
-            },
+            } else {
+                unsigned.to_string()
+            }
+        };
+
+        Self {
+            // a small negative number may be rounded to zero
+            is_negative: ele.is_negative() && str != "0",
+            str,
         }
     }
 }
 
+impl MatrixElementDisplay {
+    fn to_signed_string(&self) -> String {
+        if self.is_negative {
+            format!("-{}", self.str)
+        } else {
+            self.str.clone()
+        }
+    }
+}
+
+impl Matrix {
+    /// Returns the matrix as a Markdown table, using the column indices as headers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, -2;
+    ///     0.5, 4;
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     m.to_markdown(),
+    ///     "| 0 | 1 |\n| --- | --- |\n| 1 | -2 |\n| 0.5 | 4 |"
+    /// );
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let header = (0..self.cols_number)
+            .map(|j| j.to_string())
+            .collect::<Vec<_>>();
+        let separator = vec!["---".to_string(); self.cols_number];
+
+        let rows = self.as_rows().into_iter().map(|row| {
+            row.into_iter()
+                .map(|ele| MatrixElementDisplay::new(ele, DEFAULT_PRECISION).to_signed_string())
+                .collect::<Vec<_>>()
+        });
+
+        [header, separator]
+            .into_iter()
+            .chain(rows)
+            .map(|cells| format!("| {} |", cells.join(" | ")))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
 impl Display for Matrix {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         let space = 3;
 
         let m = self.rows_number;
+        let precision = f.precision().unwrap_or(DEFAULT_PRECISION);
 
         let cols = self
             .as_cols()
             .into_iter()
-            .map(|col| col.into_iter().map(MatrixElementDisplay::new).collect())
+            .map(|col| {
+                col.into_iter()
+                    .map(|ele| MatrixElementDisplay::new(ele, precision))
+                    .collect()
+            })
             .map(pad_strings)
             .collect::<Vec<Vec<_>>>();
 
@@ -95,6 +161,25 @@ mod tests {
     use super::*;
     use crate::{matrix, MatrixElement};
 
+    #[test]
+    fn to_markdown() {
+        let m = matrix![
+            1, -2.5, 3;
+            -1.0 / 3.0, 0, 10;
+        ];
+
+        assert_eq!(
+            m.to_markdown(),
+            r"
+| 0 | 1 | 2 |
+| --- | --- | --- |
+| 1 | -2.5 | 3 |
+| -0.33333333333333 | 0 | 10 |
+"
+            .trim()
+        );
+    }
+
     #[test]
     fn display() {
         assert_eq!(
@@ -173,6 +258,24 @@ mod tests {
             .to_string()
         );
 
+        assert_eq!(
+            format!(
+                "{:.2}",
+                matrix![
+                    -1, 2.5, 1.0/3.0;
+                    0.999, -0.001, -2.0/3.0;
+                ]
+            ),
+            r"
+┌                    ┐
+│  -1   2.5   0.33   │
+│   1   0    -0.67   │
+└                    ┘
+"
+            .trim()
+            .to_string()
+        );
+
         let m = matrix![
             1, 0;
             // in this case, the `ele.is_integer()` returns false, but the `format!("{:.14}", ele)` make it to 1.00000000000000
@@ -197,6 +300,17 @@ mod tests {
 │   1   0   │
 │   0   1   │
 └           ┘
+"
+            .trim()
+            .to_string()
+        );
+        assert_eq!(
+            format!("{:.0}", matrix![10.4, -0.4; 2.5, 1]),
+            r"
+┌            ┐
+│   10   0   │
+│   2    1   │
+└            ┘
 "
             .trim()
             .to_string()
@@ -1,11 +1,22 @@
 use std::{
     cmp::Ordering,
     fmt::{self, Display},
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
+use crate::{Error, Result};
+
 /// A matrix element.
+///
+/// Equality and ordering are exact and only partial because of `NaN`. Use [`TotalOrderElement`]
+/// to sort elements or to use them as keys of a [`BTreeMap`](std::collections::BTreeMap), and
+/// [`EpsilonOrdElement`] for an ordering within the epsilon tolerance.
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct MatrixElement {
     data: f64,
 }
@@ -15,6 +26,18 @@ impl MatrixElement {
     pub fn new(value: f64) -> Self {
         MatrixElement { data: value }
     }
+
+    /// Returns the raw [`f64`] value of the matrix element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::MatrixElement;
+    /// assert_eq!(MatrixElement::new(-2.5).value(), -2.5);
+    /// ```
+    pub fn value(&self) -> f64 {
+        self.data
+    }
 }
 
 impl Display for MatrixElement {
@@ -36,6 +59,12 @@ macro_rules! impl_from {
 }
 impl_from!(i8, i16, i32, i64, f32, f64);
 
+impl From<MatrixElement> for f64 {
+    fn from(value: MatrixElement) -> Self {
+        value.data
+    }
+}
+
 impl MatrixElement {
     /// Returns a matrix element with value `0`.
     pub fn zero() -> Self {
@@ -75,6 +104,63 @@ impl MatrixElement {
         self.data.trunc()
     }
 
+    /// Returns the nearest integer to the matrix element, rounding half-way cases away from zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::MatrixElement;
+    /// assert!(MatrixElement::new(2.5).round().epsilon_equals(&3));
+    /// assert!(MatrixElement::new(-0.9999999).round().epsilon_equals(&-1));
+    /// ```
+    pub fn round(&self) -> Self {
+        MatrixElement::new(self.data.round())
+    }
+
+    /// Returns the smallest integer greater than or equal to the matrix element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::MatrixElement;
+    /// assert!(MatrixElement::new(2.1).ceil().epsilon_equals(&3));
+    /// assert!(MatrixElement::new(-2.1).ceil().epsilon_equals(&-2));
+    /// ```
+    pub fn ceil(&self) -> Self {
+        MatrixElement::new(self.data.ceil())
+    }
+
+    /// Returns the largest integer less than or equal to the matrix element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::MatrixElement;
+    /// assert!(MatrixElement::new(2.9).floor().epsilon_equals(&2));
+    /// assert!(MatrixElement::new(-2.1).floor().epsilon_equals(&-3));
+    /// ```
+    pub fn floor(&self) -> Self {
+        MatrixElement::new(self.data.floor())
+    }
+
+    /// Restricts the matrix element to the interval `[min, max]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`, or if either bound is `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::MatrixElement;
+    /// assert!(MatrixElement::new(7.5).clamp(0, 5).epsilon_equals(&5));
+    /// assert!(MatrixElement::new(-1.0).clamp(0, 5).epsilon_equals(&0));
+    /// assert!(MatrixElement::new(2.5).clamp(0, 5).epsilon_equals(&2.5));
+    /// ```
+    pub fn clamp<T: Into<MatrixElement>, U: Into<MatrixElement>>(&self, min: T, max: U) -> Self {
+        MatrixElement::new(self.data.clamp(min.into().data, max.into().data))
+    }
+
     /// Checks if the matrix element is zero.
     pub fn is_zero(&self) -> bool {
         self.epsilon_equals(&Self::zero())
@@ -97,12 +183,12 @@ impl MatrixElement {
 
     /// Checks if the matrix element is positive.
     pub fn is_positive(&self) -> bool {
-        self > &Self::zero()
+        self.data > 0.0
     }
 
     /// Checks if the matrix element is negative.
     pub fn is_negative(&self) -> bool {
-        self < &Self::zero()
+        self.data < 0.0
     }
 
     /// Negates the matrix element.
@@ -119,6 +205,28 @@ impl MatrixElement {
         Self::zero() - *self
     }
 
+    /// Returns the sign of the matrix element: `-1`, `0` or `1`.
+    ///
+    /// Elements within epsilon of zero have sign `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::MatrixElement;
+    /// assert!(MatrixElement::new(-4.2).signum().epsilon_equals(&-1));
+    /// assert!(MatrixElement::new(0.00000000001).signum().epsilon_equals(&0));
+    /// assert!(MatrixElement::new(7.0).signum().epsilon_equals(&1));
+    /// ```
+    pub fn signum(&self) -> Self {
+        if self.is_zero() {
+            Self::zero()
+        } else if self.is_negative() {
+            Self::one().negate()
+        } else {
+            Self::one()
+        }
+    }
+
     /// Returns the inverse of the matrix element.
     ///
     /// # Panics
@@ -141,6 +249,33 @@ impl MatrixElement {
         Self::one() / *self
     }
 
+    /// Divides the matrix element by another matrix element.
+    ///
+    /// This is the fallible counterpart of the `/` operator, which panics when dividing by zero.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if `rhs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::MatrixElement;
+    /// let element = MatrixElement::new(3.0);
+    ///
+    /// assert!(element.checked_div(2).unwrap().epsilon_equals(&1.5));
+    /// element.checked_div(0).unwrap_err();
+    /// ```
+    pub fn checked_div<T: Into<MatrixElement>>(&self, rhs: T) -> Result<Self> {
+        let rhs = rhs.into();
+
+        if rhs.is_zero() {
+            return Err(Error::InvalidOperation("Cannot divide by zero"));
+        }
+
+        Ok(MatrixElement::new(self.data / rhs.data))
+    }
+
     /// Returns the absolute value of the matrix element.
     ///
     /// # Examples
@@ -152,13 +287,62 @@ impl MatrixElement {
     /// assert!(element.abs().epsilon_equals(&MatrixElement::new(3.0)));
     /// ```
     pub fn abs(&self) -> Self {
-        if *self < Self::zero() {
+        if self.is_negative() {
             self.negate()
         } else {
             *self
         }
     }
 
+    /// Returns the square root of the matrix element.
+    ///
+    /// NOTE: Like [`f64::sqrt`], the square root of a negative number is `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::MatrixElement;
+    /// let element = MatrixElement::new(9.0);
+    ///
+    /// assert!(element.sqrt().epsilon_equals(&3));
+    /// assert!(MatrixElement::new(-1.0).sqrt().value().is_nan());
+    /// ```
+    pub fn sqrt(&self) -> Self {
+        MatrixElement::new(self.data.sqrt())
+    }
+
+    /// Raises the matrix element to an integer power.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::MatrixElement;
+    /// let element = MatrixElement::new(2.0);
+    ///
+    /// assert!(element.powi(10).epsilon_equals(&1024));
+    /// assert!(element.powi(-2).epsilon_equals(&0.25));
+    /// ```
+    pub fn powi(&self, n: i32) -> Self {
+        MatrixElement::new(self.data.powi(n))
+    }
+
+    /// Raises the matrix element to a floating point power.
+    ///
+    /// NOTE: Like [`f64::powf`], a negative number raised to a non-integer power is `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::MatrixElement;
+    /// let element = MatrixElement::new(8.0);
+    ///
+    /// assert!(element.powf(1.0 / 3.0).epsilon_equals(&2));
+    /// assert!(MatrixElement::new(-8.0).powf(0.5).value().is_nan());
+    /// ```
+    pub fn powf(&self, x: f64) -> Self {
+        MatrixElement::new(self.data.powf(x))
+    }
+
     /// Checks if the matrix element is equal to another matrix element within a certain epsilon.
     ///
     /// NOTE: The epsilon value is `10e-8`.
@@ -179,10 +363,19 @@ impl MatrixElement {
     }
 
     /// Compares the matrix element to another matrix element within a certain epsilon.
+    ///
+    /// `NaN` is greater than any other value and equal to itself.
     pub fn epsilon_cmp(&self, other: &Self) -> Ordering {
+        match (self.data.is_nan(), other.data.is_nan()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Greater,
+            (false, true) => return Ordering::Less,
+            (false, false) => {}
+        }
+
         if self.epsilon_equals(other) {
             Ordering::Equal
-        } else if self < other {
+        } else if self.data < other.data {
             Ordering::Less
         } else {
             Ordering::Greater
@@ -274,6 +467,26 @@ impl DivAssign for MatrixElement {
     }
 }
 
+/// Negates the matrix element.
+///
+/// # Examples
+///
+/// ```
+/// # use rust_matrix::MatrixElement;
+/// assert!((-MatrixElement::new(3.0)).epsilon_equals(&-3));
+/// ```
+///
+/// # See also
+///
+/// * [`MatrixElement::negate`]
+impl Neg for MatrixElement {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.negate()
+    }
+}
+
 macro_rules! impl_ops {
     ($( $type:ty ),*) => {
         $(
@@ -361,6 +574,119 @@ macro_rules! impl_ops {
 }
 impl_ops!(i8, i16, i32, i64, f32, f64);
 
+/// A matrix element with a total order, so that it can be sorted or used as a key.
+///
+/// [`MatrixElement`] only implements [`PartialOrd`] because of `NaN`. This wrapper orders the
+/// values with [`f64::total_cmp`], which places positive `NaN` after positive infinity and
+/// negative `NaN` before negative infinity.
+///
+/// Unlike [`MatrixElement::epsilon_cmp`], the comparison is exact.
+///
+/// # Examples
+///
+/// ```
+/// # use rust_matrix::{MatrixElement, TotalOrderElement};
+/// let mut elements = vec![
+///     TotalOrderElement(MatrixElement::new(3.0)),
+///     TotalOrderElement(MatrixElement::new(f64::NAN)),
+///     TotalOrderElement(MatrixElement::new(-1.0)),
+/// ];
+/// elements.sort();
+///
+/// assert!(elements[0].0.epsilon_equals(&-1));
+/// assert!(elements[1].0.epsilon_equals(&3));
+/// assert!(elements[2].0.value().is_nan());
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct TotalOrderElement(pub MatrixElement);
+
+impl PartialEq for TotalOrderElement {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for TotalOrderElement {}
+
+impl PartialOrd for TotalOrderElement {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TotalOrderElement {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.data.total_cmp(&other.0.data)
+    }
+}
+
+impl From<MatrixElement> for TotalOrderElement {
+    fn from(value: MatrixElement) -> Self {
+        TotalOrderElement(value)
+    }
+}
+
+impl From<TotalOrderElement> for MatrixElement {
+    fn from(value: TotalOrderElement) -> Self {
+        value.0
+    }
+}
+
+/// A matrix element ordered within the epsilon tolerance of [`MatrixElement::epsilon_cmp`].
+///
+/// NOTE: Because of the tolerance, the comparison is not transitive: `a == b` and `b == c` do not
+///       imply `a == c` for values closer to each other than the epsilon. Sorting such values
+///       with the standard library may therefore panic or give an unspecified order, and a
+///       [`BTreeMap`](std::collections::BTreeMap) may behave inconsistently. Prefer
+///       [`TotalOrderElement`] unless the values are known to be well separated.
+///
+/// `NaN` is greater than any other value and equal to itself.
+///
+/// # Examples
+///
+/// ```
+/// # use rust_matrix::{EpsilonOrdElement, MatrixElement};
+/// let a = EpsilonOrdElement(MatrixElement::new(0.5));
+/// let b = EpsilonOrdElement(MatrixElement::new(0.50000000001));
+///
+/// assert_eq!(a, b);
+/// assert!(a < EpsilonOrdElement(MatrixElement::new(0.6)));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct EpsilonOrdElement(pub MatrixElement);
+
+impl PartialEq for EpsilonOrdElement {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for EpsilonOrdElement {}
+
+impl PartialOrd for EpsilonOrdElement {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EpsilonOrdElement {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.epsilon_cmp(&other.0)
+    }
+}
+
+impl From<MatrixElement> for EpsilonOrdElement {
+    fn from(value: MatrixElement) -> Self {
+        EpsilonOrdElement(value)
+    }
+}
+
+impl From<EpsilonOrdElement> for MatrixElement {
+    fn from(value: EpsilonOrdElement) -> Self {
+        value.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -370,4 +696,69 @@ mod tests {
     fn inverse_zero() {
         MatrixElement::zero().inverse();
     }
+
+    #[test]
+    fn exact_partial_eq() {
+        assert_ne!(MatrixElement::new(1.0), MatrixElement::new(1.00000000001));
+        assert_ne!(MatrixElement::new(f64::NAN), MatrixElement::new(f64::NAN));
+        assert!(MatrixElement::new(1.0) < MatrixElement::new(1.00000000001));
+    }
+
+    #[test]
+    fn epsilon_ord() {
+        let mut elements: Vec<EpsilonOrdElement> = [3.0, -1.0, 2.5, 0.0, -7.25]
+            .into_iter()
+            .map(|x| EpsilonOrdElement(MatrixElement::new(x)))
+            .collect();
+        elements.sort();
+
+        let sorted: Vec<f64> = elements.iter().map(|e| e.0.data).collect();
+        assert_eq!(sorted, [-7.25, -1.0, 0.0, 2.5, 3.0]);
+
+        assert_eq!(
+            EpsilonOrdElement(MatrixElement::new(1.0)),
+            EpsilonOrdElement(MatrixElement::new(1.00000000001))
+        );
+
+        let mut map = std::collections::BTreeMap::new();
+        map.insert(EpsilonOrdElement(MatrixElement::new(0.5)), "half");
+        assert_eq!(
+            map.get(&EpsilonOrdElement(MatrixElement::new(0.50000000001))),
+            Some(&"half")
+        );
+    }
+
+    #[test]
+    fn f64_round_trip() {
+        for x in [0.0, -1.5, 3.25, 1e-12, f64::MAX] {
+            assert_eq!(f64::from(MatrixElement::from(x)).to_bits(), x.to_bits());
+            assert_eq!(MatrixElement::new(x).value().to_bits(), x.to_bits());
+        }
+    }
+
+    #[test]
+    fn total_order_sort_with_nan() {
+        let values = [2.0, f64::NAN, -5.0, 0.5, f64::NEG_INFINITY, 1.0];
+
+        let mut elements: Vec<_> = values
+            .iter()
+            .map(|&x| TotalOrderElement(MatrixElement::new(x)))
+            .collect();
+        elements.sort();
+
+        let sorted: Vec<f64> = elements.iter().map(|e| e.0.data).collect();
+
+        assert_eq!(&sorted[..5], &[f64::NEG_INFINITY, -5.0, 0.5, 1.0, 2.0]);
+        assert!(sorted[5].is_nan());
+
+        // sorting a permutation gives the same order
+        let mut reversed: Vec<_> = values
+            .iter()
+            .rev()
+            .map(|&x| TotalOrderElement(MatrixElement::new(x)))
+            .collect();
+        reversed.sort();
+
+        assert_eq!(reversed, elements);
+    }
 }
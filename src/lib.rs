@@ -9,10 +9,14 @@ mod element;
 mod macro_matrix;
 mod matrix;
 mod operations;
+#[cfg(feature = "serde")]
+mod serialize;
 mod vector;
 
-pub use element::MatrixElement;
+pub use decomposition::LuFactors;
+pub use element::{EpsilonOrdElement, MatrixElement, TotalOrderElement};
 pub use matrix::Matrix;
+pub use operations::ConditionReport;
 pub use vector::Vector;
 
 /// Error types
@@ -25,12 +25,31 @@
 /// );
 /// ```
 ///
+/// Like [`vec!`], a matrix filled with a single value can be created with `matrix![value; rows, cols]`.
+///
+/// ```
+/// # use rust_matrix::{matrix, Matrix, MatrixElement};
+/// let m = matrix![7; 2, 3];
+///
+/// assert_eq!(
+///     m,
+///     matrix![
+///         7, 7, 7;
+///         7, 7, 7;
+///     ]
+/// );
+/// assert_eq!(matrix![0; 3, 4], Matrix::zero(3, 4));
+/// ```
+///
 /// # See also
 /// * [`Matrix`](crate::Matrix)
 /// * [`MatrixElement`](crate::MatrixElement)
 /// * [`vector!`](crate::vector!)
 #[macro_export]
 macro_rules! matrix {
+    ( $value:expr ; $rows:expr , $cols:expr ) => {
+        Matrix::new(vec![vec![MatrixElement::from($value); $cols]; $rows])
+    };
     ( $( $( $row:expr ),+ );+ ; ) => {
         Matrix::new(vec![
             $(
@@ -42,3 +61,26 @@ macro_rules! matrix {
         matrix![ $( $( $row ),+ );+ ; ]
     };
 }
+
+/// Create an identity matrix of the given size.
+///
+/// Unlike [`matrix!`](crate::matrix!), it does not require [`Matrix`](crate::Matrix) to be
+/// imported.
+///
+/// # Examples
+///
+/// ```
+/// use rust_matrix::{eye, Matrix};
+///
+/// assert_eq!(eye!(3), Matrix::identity(3));
+/// ```
+///
+/// # See also
+/// * [`Matrix::identity`](crate::Matrix::identity)
+/// * [`matrix!`](crate::matrix!)
+#[macro_export]
+macro_rules! eye {
+    ( $n:expr ) => {
+        $crate::Matrix::identity($n)
+    };
+}
@@ -11,8 +11,10 @@ pub struct Matrix {
     /// The number of rows.
     pub rows_number: usize,
 
-    /// The elements of the matrix.
-    pub elements: Vec<Vec<MatrixElement>>,
+    /// The elements of the matrix, stored row by row.
+    ///
+    /// The element at `(row, col)` is `elements[row * cols_number + col]`.
+    pub(crate) elements: Vec<MatrixElement>,
 }
 
 impl From<Vec<Vec<MatrixElement>>> for Matrix {
@@ -27,6 +29,28 @@ impl From<Vec<Vector>> for Matrix {
     }
 }
 
+macro_rules! impl_from_array {
+    ($( $type:ty ),*) => {
+        $(
+            /// Creates a matrix from an array of rows.
+            ///
+            /// An array without rows is rejected at compile time, since a matrix has at least one
+            /// row.
+            impl<const M: usize, const N: usize> From<[[$type; N]; M]> for Matrix {
+                fn from(value: [[$type; N]; M]) -> Self {
+                    const { assert!(M > 0, "Matrix must have at least one row") };
+
+                    value
+                        .into_iter()
+                        .map(|row| row.into_iter().map(MatrixElement::from).collect::<Vec<_>>())
+                        .collect()
+                }
+            }
+        )*
+    };
+}
+impl_from_array!(i32, f64);
+
 impl FromIterator<Vec<MatrixElement>> for Matrix {
     fn from_iter<T: IntoIterator<Item = Vec<MatrixElement>>>(iter: T) -> Self {
         Matrix::new(iter.into_iter().collect())
@@ -45,20 +69,49 @@ impl Matrix {
     /// # Panics
     ///
     /// Panics if the list is empty or if the rows have different lengths.
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::try_new`]
     pub fn new(elements: Vec<Vec<MatrixElement>>) -> Self {
+        Self::try_new(elements).unwrap()
+    }
+
+    /// Creates a new matrix from a list of elements.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the list is empty or if the rows have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{Matrix, MatrixElement};
+    /// let row = vec![MatrixElement::one(), MatrixElement::zero()];
+    ///
+    /// assert!(Matrix::try_new(vec![row.clone(), row.clone()]).is_ok());
+    /// assert!(Matrix::try_new(vec![row, vec![MatrixElement::one()]]).is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::new`]
+    pub fn try_new(elements: Vec<Vec<MatrixElement>>) -> Result<Self> {
         if elements.is_empty() {
-            panic!("Matrix must have at least one row");
+            return Err(Error::InvalidOperation("Matrix must have at least one row"));
         }
 
         if elements.iter().any(|row| row.len() != elements[0].len()) {
-            panic!("All rows must have the same length");
+            return Err(Error::InvalidOperation(
+                "All rows must have the same length",
+            ));
         }
 
-        Self {
+        Ok(Self {
             cols_number: elements[0].len(),
             rows_number: elements.len(),
-            elements,
-        }
+            elements: elements.into_iter().flatten().collect(),
+        })
     }
 
     /// Creates a new matrix from a list of columns.
@@ -95,16 +148,12 @@ impl Matrix {
         let rows_number = cols[0].len();
         let cols_number = cols.len();
 
-        let mut elements = Vec::new();
+        let mut elements = Vec::with_capacity(rows_number * cols_number);
 
         for i in 0..rows_number {
-            let mut row = Vec::new();
-
             for col in cols.iter() {
-                row.push(col[i]);
+                elements.push(col[i]);
             }
-
-            elements.push(row);
         }
 
         Self {
@@ -114,6 +163,32 @@ impl Matrix {
         }
     }
 
+    /// Creates a new matrix with the given size, filling the element at `(i, j)` with `f(i, j)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let identity = Matrix::from_fn(3, 3, |i, j| {
+    ///     if i == j {
+    ///         MatrixElement::one()
+    ///     } else {
+    ///         MatrixElement::zero()
+    ///     }
+    /// });
+    ///
+    /// assert!(identity.epsilon_equals(&Matrix::identity(3)));
+    /// ```
+    pub fn from_fn<F: Fn(usize, usize) -> MatrixElement>(rows: usize, cols: usize, f: F) -> Self {
+        (0..rows)
+            .map(|i| (0..cols).map(|j| f(i, j)).collect::<Vec<_>>())
+            .collect()
+    }
+
     /// Creates a new matrix with the given size of which all elements is zero.
     ///
     /// # Examples
@@ -153,7 +228,7 @@ impl Matrix {
         let mut matrix = Self::zero(size, size);
 
         for i in 0..size {
-            matrix.elements[i][i] = MatrixElement::one();
+            matrix.elements[i * size + i] = MatrixElement::one();
         }
 
         matrix
@@ -212,6 +287,7 @@ impl Matrix {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::matrix;
 
     #[test]
     #[should_panic]
@@ -227,4 +303,41 @@ mod tests {
             vec![MatrixElement::new(1.0)],
         ]);
     }
+
+    #[test]
+    fn try_new_empty() {
+        Matrix::try_new(Vec::new()).unwrap_err();
+    }
+
+    #[test]
+    fn try_new_diff_length() {
+        Matrix::try_new(vec![
+            vec![MatrixElement::new(1.0), MatrixElement::new(2.0)],
+            vec![MatrixElement::new(1.0)],
+        ])
+        .unwrap_err();
+    }
+
+    #[test]
+    fn from_fn() {
+        let m = Matrix::from_fn(2, 3, |i, j| MatrixElement::from((i * 3 + j) as i32));
+
+        assert!(m.epsilon_equals(&matrix![
+            0, 1, 2;
+            3, 4, 5;
+        ]));
+    }
+
+    #[test]
+    fn from_array() {
+        let m = Matrix::from([[1, 2], [3, 4]]);
+
+        assert_eq!(m.rows_number, 2);
+        assert_eq!(m.cols_number, 2);
+        assert!(m.epsilon_equals(&matrix![1, 2; 3, 4]));
+
+        let m: Matrix = [[1.5, -2.0, 0.25]].into();
+
+        assert!(m.epsilon_equals(&matrix![1.5, -2, 0.25]));
+    }
 }
@@ -1,15 +1,49 @@
 mod add;
 mod adj;
+mod balance;
+mod block;
+mod charpoly;
 mod cofactor;
+mod condition;
 mod det;
+mod diagonal;
+mod dominance;
+mod edit;
+mod eigen;
 mod equals;
+mod exact;
+mod function;
+mod gershgorin;
 mod get;
+mod givens;
+mod householder;
 mod inverse;
+mod kronecker;
+mod map;
 mod mul;
+#[cfg(feature = "rayon")]
+mod mul_parallel;
 mod mul_vec;
+mod norm;
+mod packed;
+mod permanent;
+mod pow;
+mod predicate;
+mod rank;
 mod reduce;
+mod rotation;
 mod row_ops;
 mod scale;
 mod set;
+mod similarity;
+mod solve;
+mod sort;
+mod space;
+mod special;
+mod strassen;
 mod swap;
+mod trace;
 mod transpose;
+mod unique;
+
+pub use condition::ConditionReport;
@@ -1,6 +1,6 @@
 use std::ops::Add;
 
-use crate::{Error, Matrix, Result};
+use crate::{Error, Matrix, Result, Vector};
 
 impl Matrix {
     /// Returns a new matrix that is the sum of this matrix and another matrix.
@@ -51,6 +51,54 @@ impl Matrix {
 
         Ok(result)
     }
+
+    /// Adds the outer product `u * vᵀ` to the matrix in place, without allocating it.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the length of `u` is different from the number of rows, or if the length
+    /// of `v` is different from the number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let mut m = Matrix::identity(2);
+    /// m.add_outer(&vector![1, 2], &vector![3, 4]).unwrap();
+    ///
+    /// assert!(m.epsilon_equals(&matrix![
+    ///     4, 4;
+    ///     6, 9;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Vector::outer`]
+    pub fn add_outer(&mut self, u: &Vector, v: &Vector) -> Result<()> {
+        if u.len() != self.rows_number || v.len() != self.cols_number {
+            return Err(Error::InvalidOperation(
+                "Vector lengths must match the matrix dimensions to add an outer product",
+            ));
+        }
+
+        // there is nothing to add, and `chunks_mut` panics on a chunk size of zero
+        if self.cols_number == 0 {
+            return Ok(());
+        }
+
+        for (row, &x) in self
+            .elements
+            .chunks_mut(self.cols_number)
+            .zip(u.data.iter())
+        {
+            for (element, &y) in row.iter_mut().zip(v.data.iter()) {
+                *element += x * y;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl Add for Matrix {
@@ -64,7 +112,7 @@ impl Add for Matrix {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{matrix, MatrixElement};
+    use crate::{matrix, vector, MatrixElement};
 
     #[test]
     fn add() {
@@ -82,4 +130,24 @@ mod tests {
             14, 16, 18;
         ]));
     }
+
+    #[test]
+    fn add_outer() {
+        let (u1, v1) = (vector![1, -2, 3], vector![4, 0.5]);
+        let (u2, v2) = (vector![0, 1, 2], vector![-1, 3]);
+
+        let mut m = Matrix::zero(3, 2);
+        m.add_outer(&u1, &v1).unwrap();
+        m.add_outer(&u2, &v2).unwrap();
+
+        assert!(m.epsilon_equals(&(u1.outer(&v1) + u2.outer(&v2))));
+
+        m.add_outer(&v1, &u1).unwrap_err();
+
+        let mut empty = Matrix::zero(2, 0);
+        empty
+            .add_outer(&vector![1, 2], &Vector::new(vec![]))
+            .unwrap();
+        assert_eq!(empty.cols_number, 0);
+    }
 }
@@ -0,0 +1,150 @@
+use crate::{Error, Matrix, MatrixElement, Result, Vector};
+
+/// The base of the floating point representation, so that balancing introduces no rounding errors.
+const RADIX: f64 = 2.0;
+
+/// The maximum number of sweeps over the rows in [`Matrix::balance`].
+const MAX_SWEEPS: usize = 100;
+
+impl Matrix {
+    /// Balances the matrix with the Parlett–Reinsch algorithm.
+    ///
+    /// Returns the balanced matrix `B = D⁻¹ * A * D` and the diagonal of `D`. The scale factors are
+    /// powers of two chosen so that the norms of each row and the matching column are close, which
+    /// improves the accuracy of eigenvalue algorithms. `B` is similar to `A`, so it has the same
+    /// eigenvalues. Balancing stops after a fixed number of sweeps, which leaves `B` similar to `A`
+    /// even if it is not fully balanced yet.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square, or if any element is infinite or `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let m = matrix![
+    ///     1, 1024;
+    ///     0.0009765625, 1;
+    /// ];
+    ///
+    /// let (balanced, scale) = m.balance().unwrap();
+    ///
+    /// assert!(balanced.epsilon_equals(&matrix![
+    ///     1, 1;
+    ///     1, 1;
+    /// ]));
+    /// assert!(scale.epsilon_equals(&vector![1024, 1]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Balancing](https://en.wikipedia.org/wiki/Eigenvalue_algorithm#Balancing)
+    pub fn balance(&self) -> Result<(Self, Vector)> {
+        self.assert_square("Only square matrices can be balanced")?;
+
+        if self.elements.iter().any(|x| !x.value().is_finite()) {
+            return Err(Error::InvalidOperation(
+                "Only matrices with finite elements can be balanced",
+            ));
+        }
+
+        let n = self.rows_number;
+
+        let mut a: Vec<f64> = self.elements.iter().map(|x| x.value()).collect();
+        let mut scale = vec![1.0; n];
+
+        for _ in 0..MAX_SWEEPS {
+            let mut converged = true;
+
+            for i in 0..n {
+                let mut c = 0.0;
+                let mut r = 0.0;
+                for j in (0..n).filter(|&j| j != i) {
+                    c += a[j * n + i].abs();
+                    r += a[i * n + j].abs();
+                }
+
+                if c == 0.0 || r == 0.0 {
+                    continue;
+                }
+
+                let s = c + r;
+                let mut f = 1.0;
+
+                let mut g = r / RADIX;
+                while c < g {
+                    f *= RADIX;
+                    c *= RADIX * RADIX;
+                }
+
+                g = r * RADIX;
+                while c > g {
+                    f /= RADIX;
+                    c /= RADIX * RADIX;
+                }
+
+                if (c + r) / f < 0.95 * s {
+                    converged = false;
+                    scale[i] *= f;
+
+                    for j in 0..n {
+                        a[i * n + j] /= f;
+                        a[j * n + i] *= f;
+                    }
+                }
+            }
+
+            if converged {
+                break;
+            }
+        }
+
+        let balanced = Matrix::from_fn(n, n, |i, j| MatrixElement::new(a[i * n + j]));
+
+        Ok((
+            balanced,
+            scale.into_iter().map(MatrixElement::new).collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, Matrix, MatrixElement};
+
+    #[test]
+    fn balance_preserves_similarity() {
+        let m = matrix![
+            1, 100, 10000;
+            0.01, 1, 100;
+            0.0001, 0.01, 1;
+        ];
+
+        let (balanced, scale) = m.balance().unwrap();
+
+        assert!(balanced
+            .trace()
+            .unwrap()
+            .epsilon_equals(&m.trace().unwrap()));
+        assert!(balanced.det().unwrap().epsilon_equals(&m.det().unwrap()));
+        assert!(balanced.frobenius_norm().value() < m.frobenius_norm().value());
+
+        // B = D⁻¹ * A * D
+        let d = Matrix::from_fn(3, 3, |i, j| {
+            if i == j {
+                scale[i]
+            } else {
+                MatrixElement::zero()
+            }
+        });
+        assert!((d.clone() * balanced).epsilon_equals(&(m * d)));
+    }
+
+    #[test]
+    fn balance_invalid() {
+        matrix![1, 2, 3].balance().unwrap_err();
+        matrix![1, f64::INFINITY; 1, 1].balance().unwrap_err();
+        matrix![1, f64::NAN; 1, 1].balance().unwrap_err();
+    }
+}
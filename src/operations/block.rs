@@ -0,0 +1,164 @@
+use crate::Matrix;
+
+impl Matrix {
+    /// Returns the direct sum `self ⊕ other`, which is the block diagonal matrix with `self` in the
+    /// top left corner and `other` in the bottom right corner.
+    ///
+    /// The result has size `(m1 + m2) × (n1 + n2)`, and the off-diagonal blocks are zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let a = matrix![1, 2];
+    /// let b = matrix![
+    ///     3;
+    ///     4;
+    /// ];
+    ///
+    /// assert!(a.direct_sum(&b).epsilon_equals(&matrix![
+    ///     1, 2, 0;
+    ///     0, 0, 3;
+    ///     0, 0, 4;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Direct sum of matrices](https://en.wikipedia.org/wiki/Matrix_addition#Direct_sum)
+    pub fn direct_sum(&self, other: &Self) -> Self {
+        Self::_block_diagonal(&[self, other])
+    }
+
+    /// Returns the block diagonal matrix with the given blocks along its diagonal.
+    ///
+    /// The blocks may be rectangular. The result has as many rows and columns as all blocks
+    /// together, and the off-diagonal blocks are zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `blocks` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = Matrix::block_diagonal(&[matrix![1], matrix![2, 3], matrix![4; 5]]);
+    ///
+    /// assert!(m.epsilon_equals(&matrix![
+    ///     1, 0, 0, 0;
+    ///     0, 2, 3, 0;
+    ///     0, 0, 0, 4;
+    ///     0, 0, 0, 5;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Block diagonal matrix](https://en.wikipedia.org/wiki/Block_matrix#Block_diagonal_matrices)
+    /// * [`Matrix::direct_sum`]
+    pub fn block_diagonal(blocks: &[Matrix]) -> Self {
+        Self::_block_diagonal(&blocks.iter().collect::<Vec<_>>())
+    }
+
+    fn _block_diagonal(blocks: &[&Matrix]) -> Self {
+        if blocks.is_empty() {
+            panic!("Block diagonal matrix must have at least one block");
+        }
+
+        let mut result = Matrix::zero(
+            blocks.iter().map(|block| block.rows_number).sum(),
+            blocks.iter().map(|block| block.cols_number).sum(),
+        );
+        let cols = result.cols_number;
+
+        let mut row_offset = 0;
+        let mut col_offset = 0;
+        for block in blocks {
+            // slice the rows by index, since `chunks` panics on blocks without columns
+            let width = block.cols_number;
+            for i in 0..block.rows_number {
+                let start = (row_offset + i) * cols + col_offset;
+                result.elements[start..start + width]
+                    .copy_from_slice(&block.elements[i * width..(i + 1) * width]);
+            }
+
+            row_offset += block.rows_number;
+            col_offset += block.cols_number;
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, Matrix, MatrixElement};
+
+    #[test]
+    fn block_diagonal() {
+        let a = matrix![
+            2, 1;
+            1, 3;
+        ];
+        let b = matrix![
+            4, -2;
+            1, 1;
+        ];
+
+        let m = Matrix::block_diagonal(&[a.clone(), b.clone()]);
+
+        assert!(m.epsilon_equals(&matrix![
+            2, 1, 0, 0;
+            1, 3, 0, 0;
+            0, 0, 4, -2;
+            0, 0, 1, 1;
+        ]));
+        assert!(m
+            .det()
+            .unwrap()
+            .epsilon_equals(&(a.det().unwrap() * b.det().unwrap())));
+        assert!(m.epsilon_equals(&a.direct_sum(&b)));
+
+        // a block without columns only adds zero rows
+        assert!(
+            Matrix::block_diagonal(&[matrix![1, 2], Matrix::zero(1, 0)]).epsilon_equals(&matrix![
+                1, 2;
+                0, 0;
+            ])
+        );
+    }
+
+    #[test]
+    fn direct_sum_det() {
+        let a = matrix![
+            2, 1;
+            1, 3;
+        ];
+        let b = matrix![
+            1, 2, 0;
+            0, 1, 4;
+            5, 0, 1;
+        ];
+
+        let sum = a.direct_sum(&b);
+
+        assert_eq!(sum.rows_number, 5);
+        assert_eq!(sum.cols_number, 5);
+        assert!(sum
+            .det()
+            .unwrap()
+            .epsilon_equals(&(a.det().unwrap() * b.det().unwrap())));
+    }
+
+    #[test]
+    fn direct_sum_without_columns() {
+        let sum = Matrix::zero(2, 0).direct_sum(&matrix![1, 2]);
+
+        assert!(sum.epsilon_equals(&matrix![
+            0, 0;
+            0, 0;
+            1, 2;
+        ]));
+    }
+}
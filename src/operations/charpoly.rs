@@ -0,0 +1,101 @@
+use crate::{Matrix, MatrixElement, Result};
+
+impl Matrix {
+    /// Returns the coefficients of the characteristic polynomial `det(A - xI)`, from the highest
+    /// degree to the lowest.
+    ///
+    /// The coefficients are computed by the Faddeev–LeVerrier algorithm. The leading coefficient
+    /// is `(-1)^n`, where `n` is the size of the matrix.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     2, 1;
+    ///     1, 2;
+    /// ];
+    ///
+    /// // x² - 4x + 3
+    /// let coefficients = m.characteristic_polynomial().unwrap();
+    ///
+    /// assert!(coefficients[0].epsilon_equals(&1));
+    /// assert!(coefficients[1].epsilon_equals(&-4));
+    /// assert!(coefficients[2].epsilon_equals(&3));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Characteristic polynomial](https://en.wikipedia.org/wiki/Characteristic_polynomial)
+    /// * Wikipedia: [Faddeev–LeVerrier algorithm](https://en.wikipedia.org/wiki/Faddeev%E2%80%93LeVerrier_algorithm)
+    pub fn characteristic_polynomial(&self) -> Result<Vec<MatrixElement>> {
+        self.assert_square("Only square matrices have characteristic polynomials")?;
+
+        let n = self.rows_number;
+
+        // coefficients of det(xI - A), which is monic
+        let mut coefficients = vec![MatrixElement::one()];
+        let mut m = Matrix::zero(n, n);
+
+        for k in 1..=n {
+            // INFO: safe to unwrap, `coefficients` is never empty
+            let previous = *coefficients.last().unwrap();
+
+            m = self
+                .multiply(&m)?
+                .add_s(&Matrix::identity(n).scale(previous))?;
+
+            let coefficient = self.multiply(&m)?.trace()? / MatrixElement::from(k as f64);
+            coefficients.push(coefficient.negate());
+        }
+
+        if !n.is_multiple_of(2) {
+            coefficients = coefficients.into_iter().map(|c| c.negate()).collect();
+        }
+
+        Ok(coefficients)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, Matrix, MatrixElement};
+
+    #[test]
+    fn characteristic_polynomial_2x2() {
+        let m = matrix![
+            3, -1;
+            4, 7;
+        ];
+
+        let coefficients = m.characteristic_polynomial().unwrap();
+
+        assert_eq!(coefficients.len(), 3);
+        assert!(coefficients[0].is_one());
+        assert!(coefficients[1].epsilon_equals(&m.trace().unwrap().negate()));
+        assert!(coefficients[2].epsilon_equals(&m.det().unwrap()));
+    }
+
+    #[test]
+    fn characteristic_polynomial_3x3() {
+        let m = matrix![
+            2, 0, 0;
+            0, 3, 4;
+            0, 4, 9;
+        ];
+
+        // (2 - x)(x² - 12x + 11) = -x³ + 14x² - 35x + 22
+        let coefficients = m.characteristic_polynomial().unwrap();
+
+        assert!(coefficients[0].epsilon_equals(&-1));
+        assert!(coefficients[1].epsilon_equals(&14));
+        assert!(coefficients[2].epsilon_equals(&-35));
+        assert!(coefficients[3].epsilon_equals(&22));
+
+        matrix![1, 2, 3].characteristic_polynomial().unwrap_err();
+    }
+}
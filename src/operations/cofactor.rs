@@ -40,9 +40,93 @@ impl Matrix {
         Ok(Self::new(elements))
     }
 
+    /// Returns the determinant of the matrix by cofactor expansion along the given row.
+    ///
+    /// Zero elements are skipped, so expanding along a row with many zeros computes fewer minors.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square or if the row index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 2, 3;
+    ///     0, 4, 0;
+    ///     5, 6, 7;
+    /// ];
+    ///
+    /// assert!(m.det_cofactor_along_row(1).unwrap().epsilon_equals(&m.det().unwrap()));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Laplace expansion](https://en.wikipedia.org/wiki/Laplace_expansion)
+    /// * [`Matrix::det_cofactor_along_col`]
+    /// * [`Matrix::det`]
+    pub fn det_cofactor_along_row(&self, row: usize) -> Result<MatrixElement> {
+        self.assert_square("Only square matrices have determinants")?;
+        self.assert_index(row, 0)?;
+
+        let mut det = MatrixElement::zero();
+        for col in 0..self.cols_number {
+            let element = self.get(row, col)?;
+
+            if !element.is_zero() {
+                det += element * self.get_cofactor(row, col)?;
+            }
+        }
+
+        Ok(det)
+    }
+
+    /// Returns the determinant of the matrix by cofactor expansion along the given column.
+    ///
+    /// Zero elements are skipped, so expanding along a column with many zeros computes fewer minors.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square or if the column index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 0, 3;
+    ///     2, 4, 0;
+    ///     5, 0, 7;
+    /// ];
+    ///
+    /// assert!(m.det_cofactor_along_col(1).unwrap().epsilon_equals(&m.det().unwrap()));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Laplace expansion](https://en.wikipedia.org/wiki/Laplace_expansion)
+    /// * [`Matrix::det_cofactor_along_row`]
+    /// * [`Matrix::det`]
+    pub fn det_cofactor_along_col(&self, col: usize) -> Result<MatrixElement> {
+        self.assert_square("Only square matrices have determinants")?;
+        self.assert_index(0, col)?;
+
+        let mut det = MatrixElement::zero();
+        for row in 0..self.rows_number {
+            let element = self.get(row, col)?;
+
+            if !element.is_zero() {
+                det += element * self.get_cofactor(row, col)?;
+            }
+        }
+
+        Ok(det)
+    }
+
     /// Returns the cofactor of the matrix element at the given row and column.
     pub fn get_cofactor(&self, row: usize, col: usize) -> Result<MatrixElement> {
-        let sign = if (row + col) % 2 == 0 { 1 } else { -1 };
+        let sign = if (row + col).is_multiple_of(2) { 1 } else { -1 };
 
         Ok(sign * self.get_minor(row, col)?)
     }
@@ -51,27 +135,7 @@ impl Matrix {
     pub fn get_minor(&self, row: usize, col: usize) -> Result<MatrixElement> {
         self.assert_index(row, col)?;
 
-        let mut elements = Vec::new();
-
-        for i in 0..self.rows_number {
-            if i == row {
-                continue;
-            }
-
-            let mut row_elements = Vec::new();
-
-            for j in 0..self.cols_number {
-                if j == col {
-                    continue;
-                }
-
-                row_elements.push(self.get(i, j)?);
-            }
-
-            elements.push(row_elements);
-        }
-
-        Self::new(elements).det()
+        self.delete_row(row)?.delete_col(col)?.det()
     }
 }
 
@@ -94,4 +158,33 @@ mod tests {
             -11, -2.5, 0.5;
         ]))
     }
+
+    #[test]
+    fn det_cofactor_along() {
+        let m = matrix![
+            3, -7, 8, 9, -6;
+            0, 2, -5, 7, 3;
+            0, 0, 1, 5, 0;
+            0, 0, 2, 4, -1;
+            0, 0, 0, -2, 0;
+        ];
+        let det = m.det().unwrap();
+
+        for i in 0..5 {
+            assert!(m.det_cofactor_along_row(i).unwrap().epsilon_equals(&det));
+            assert!(m.det_cofactor_along_col(i).unwrap().epsilon_equals(&det));
+        }
+
+        // only one minor is needed for the last row
+        assert!(m
+            .det_cofactor_along_row(4)
+            .unwrap()
+            .epsilon_equals(&(MatrixElement::from(-2) * m.get_cofactor(4, 3).unwrap())));
+
+        m.det_cofactor_along_row(5).unwrap_err();
+        m.det_cofactor_along_col(5).unwrap_err();
+        matrix![1, 2, 3; 4, 5, 6]
+            .det_cofactor_along_row(0)
+            .unwrap_err();
+    }
 }
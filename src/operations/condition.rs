@@ -0,0 +1,173 @@
+use crate::{Error, Matrix, MatrixElement, Result};
+
+/// A summary of the properties of a matrix that affect how well it can be solved or inverted.
+///
+/// # See also
+///
+/// * [`Matrix::condition_report`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConditionReport {
+    /// The rank of the matrix.
+    pub rank: usize,
+
+    /// The determinant, or `None` if the matrix is not square.
+    pub determinant: Option<MatrixElement>,
+
+    /// The condition number in the 2-norm, which is infinite for rank-deficient matrices.
+    pub condition_number: MatrixElement,
+
+    /// Whether the matrix is symmetric.
+    pub is_symmetric: bool,
+
+    /// Whether the matrix is orthogonal.
+    pub is_orthogonal: bool,
+
+    /// Whether the matrix is symmetric positive-definite.
+    pub is_positive_definite: bool,
+
+    /// The Gershgorin bound on the spectral radius, or `None` if the matrix is not square.
+    pub spectral_radius_bound: Option<MatrixElement>,
+}
+
+impl Matrix {
+    /// Returns the condition number of the matrix in the 2-norm, which is the ratio of its largest
+    /// to its smallest singular value.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the smallest singular value is zero, i.e. the condition number is
+    /// infinite, or if the matrix has no columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     3, 0;
+    ///     0, -0.5;
+    /// ];
+    ///
+    /// assert!(m.condition_number_2norm().unwrap().epsilon_equals(&6));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Condition number](https://en.wikipedia.org/wiki/Condition_number#Matrices)
+    /// * [`Matrix::singular_values`]
+    pub fn condition_number_2norm(&self) -> Result<MatrixElement> {
+        let singular_values = self.singular_values();
+
+        let (Some(&largest), Some(&smallest)) =
+            (singular_values.data.first(), singular_values.data.last())
+        else {
+            return Err(Error::InvalidOperation(
+                "Empty matrices have no singular values",
+            ));
+        };
+
+        if smallest.is_zero() {
+            return Err(Error::InvalidOperation(
+                "The condition number of a rank-deficient matrix is infinite",
+            ));
+        }
+
+        Ok(largest / smallest)
+    }
+
+    /// Returns a [`ConditionReport`] which bundles the common checks on a matrix.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if any of the underlying computations fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     2, -1;
+    ///     -1, 2;
+    /// ];
+    ///
+    /// let report = m.condition_report().unwrap();
+    ///
+    /// assert_eq!(report.rank, 2);
+    /// assert!(report.condition_number.epsilon_equals(&3));
+    /// assert!(report.is_positive_definite);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::rank`]
+    /// * [`Matrix::det`]
+    /// * [`Matrix::condition_number_2norm`]
+    /// * [`Matrix::spectral_radius_bound`]
+    pub fn condition_report(&self) -> Result<ConditionReport> {
+        let is_square = self.rows_number == self.cols_number;
+
+        let determinant = if is_square { Some(self.det()?) } else { None };
+        let spectral_radius_bound = if is_square {
+            Some(self.spectral_radius_bound()?)
+        } else {
+            None
+        };
+
+        let condition_number = self
+            .condition_number_2norm()
+            .unwrap_or(MatrixElement::new(f64::INFINITY));
+
+        Ok(ConditionReport {
+            rank: self.rank(),
+            determinant,
+            condition_number,
+            is_symmetric: self.is_symmetric(),
+            is_orthogonal: self.is_orthogonal(),
+            is_positive_definite: self.is_positive_definite(),
+            spectral_radius_bound,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, Matrix, MatrixElement};
+
+    #[test]
+    fn condition_number_2norm() {
+        assert!(Matrix::identity(4)
+            .condition_number_2norm()
+            .unwrap()
+            .is_one());
+
+        let m = matrix![
+            -2, 0, 0;
+            0, 8, 0;
+            0, 0, 0.5;
+        ];
+        assert!(m.condition_number_2norm().unwrap().epsilon_equals(&16));
+
+        matrix![1, 2; 2, 4].condition_number_2norm().unwrap_err();
+        Matrix::zero(1, 0).condition_number_2norm().unwrap_err();
+    }
+
+    #[test]
+    fn condition_report() {
+        let report = Matrix::identity(3).condition_report().unwrap();
+
+        assert_eq!(report.rank, 3);
+        assert!(report.determinant.unwrap().is_one());
+        assert!(report.condition_number.is_one());
+        assert!(report.is_symmetric);
+        assert!(report.is_orthogonal);
+        assert!(report.is_positive_definite);
+        assert!(report.spectral_radius_bound.unwrap().is_one());
+
+        let report = matrix![1, 2, 3; 2, 4, 6].condition_report().unwrap();
+
+        assert_eq!(report.rank, 1);
+        assert!(report.determinant.is_none());
+        assert!(report.condition_number.value().is_infinite());
+        assert!(!report.is_symmetric);
+        assert!(report.spectral_radius_bound.is_none());
+    }
+}
@@ -49,7 +49,7 @@ impl Matrix {
         }
 
         // the sign of it is (-1)^r where r is the times of row swaps
-        let sign = if swap_count % 2 == 0 { 1 } else { -1 };
+        let sign = if swap_count.is_multiple_of(2) { 1 } else { -1 };
 
         Ok(sign * det)
     }
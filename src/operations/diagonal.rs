@@ -0,0 +1,109 @@
+use crate::{Matrix, MatrixElement, Vector};
+
+impl Matrix {
+    /// Returns an iterator over the elements on the main diagonal.
+    ///
+    /// The iterator yields `min(rows_number, cols_number)` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 2, 3;
+    ///     4, 5, 6;
+    /// ];
+    ///
+    /// let diagonal: Vec<_> = m.diagonal_iter().collect();
+    ///
+    /// assert_eq!(diagonal, [1, 5].map(MatrixElement::from));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::diagonal`]
+    pub fn diagonal_iter(&self) -> impl Iterator<Item = MatrixElement> + '_ {
+        self.elements
+            .iter()
+            .step_by(self.cols_number + 1)
+            .take(self.rows_number.min(self.cols_number))
+            .copied()
+    }
+
+    /// Returns the elements on the main diagonal as a vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let m = matrix![
+    ///     1, 2;
+    ///     3, 4;
+    ///     5, 6;
+    /// ];
+    ///
+    /// assert!(m.diagonal().epsilon_equals(&vector![1, 4]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::diagonal_iter`]
+    pub fn diagonal(&self) -> Vector {
+        self.diagonal_iter().collect()
+    }
+
+    /// Returns the product of the elements on the main diagonal.
+    ///
+    /// For triangular matrices, this is the determinant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     2, 7;
+    ///     0, 3;
+    /// ];
+    ///
+    /// assert!(m.diagonal_product().epsilon_equals(&6));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::diagonal_iter`]
+    pub fn diagonal_product(&self) -> MatrixElement {
+        self.diagonal_iter()
+            .fold(MatrixElement::one(), |acc, x| acc * x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, Matrix, MatrixElement};
+
+    #[test]
+    fn diagonal_iter() {
+        for m in [
+            matrix![
+                1, 2, 3;
+                4, 5, 6;
+                7, 8, 9;
+            ],
+            matrix![
+                1, 2, 3, 4;
+                5, 6, 7, 8;
+            ],
+            matrix![
+                1, 2;
+                3, 4;
+                5, 6;
+            ],
+            matrix![1; 2; 3],
+        ] {
+            let collected: Vec<_> = m.diagonal_iter().collect();
+
+            assert_eq!(collected.len(), m.rows_number.min(m.cols_number));
+            assert_eq!(collected, m.diagonal().data);
+        }
+    }
+}
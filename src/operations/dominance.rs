@@ -0,0 +1,92 @@
+use crate::{Matrix, MatrixElement, Result};
+
+impl Matrix {
+    /// Returns the minimum over all rows of `|A[i,i]| / Σ_{j≠i} |A[i,j]|`.
+    ///
+    /// A ratio of at least `1` means that the matrix is diagonally dominant, which is a cheap hint
+    /// that iterative solvers such as Jacobi or Gauss–Seidel will converge. Rows without any
+    /// off-diagonal element do not limit the ratio, so a diagonal matrix has an infinite ratio.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     4, 1, 1;
+    ///     1, 6, 2;
+    ///     0, 3, 6;
+    /// ];
+    ///
+    /// assert!(m.diagonal_dominance_ratio().unwrap().epsilon_equals(&2));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Diagonally dominant matrix](https://en.wikipedia.org/wiki/Diagonally_dominant_matrix)
+    pub fn diagonal_dominance_ratio(&self) -> Result<MatrixElement> {
+        self.assert_square("Only square matrices can be diagonally dominant")?;
+
+        let mut ratio = MatrixElement::new(f64::INFINITY);
+
+        for (i, row) in self.as_rows().into_iter().enumerate() {
+            let off_diagonal = row
+                .data
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .fold(MatrixElement::zero(), |acc, (_, x)| acc + x.abs());
+
+            if off_diagonal.is_zero() {
+                continue;
+            }
+
+            let row_ratio = row[i].abs() / off_diagonal;
+            if row_ratio < ratio {
+                ratio = row_ratio;
+            }
+        }
+
+        Ok(ratio)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, Matrix, MatrixElement};
+
+    #[test]
+    fn diagonal_dominance_ratio() {
+        let dominant = matrix![
+            10, -2, 3;
+            1, 8, -4;
+            -2, 2, 5;
+        ];
+
+        assert!(dominant
+            .diagonal_dominance_ratio()
+            .unwrap()
+            .epsilon_ge(&MatrixElement::one()));
+
+        let not_dominant = matrix![
+            1, 2;
+            3, 4;
+        ];
+
+        assert!(not_dominant
+            .diagonal_dominance_ratio()
+            .unwrap()
+            .epsilon_equals(&0.5));
+
+        assert!(Matrix::identity(3)
+            .diagonal_dominance_ratio()
+            .unwrap()
+            .value()
+            .is_infinite());
+
+        matrix![1, 2, 3].diagonal_dominance_ratio().unwrap_err();
+    }
+}
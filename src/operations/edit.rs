@@ -0,0 +1,245 @@
+use crate::{Error, Matrix, Result, Vector};
+
+impl Matrix {
+    /// Returns a copy of the matrix without the given row.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the row index is out of bounds, or if the matrix has only one row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 2;
+    ///     3, 4;
+    ///     5, 6;
+    /// ];
+    ///
+    /// assert!(m.delete_row(1).unwrap().epsilon_equals(&matrix![
+    ///     1, 2;
+    ///     5, 6;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::insert_row`]
+    /// * [`Matrix::delete_col`]
+    pub fn delete_row(&self, row: usize) -> Result<Self> {
+        self.assert_index(row, 0)?;
+
+        if self.rows_number == 1 {
+            return Err(Error::InvalidOperation("Matrix must have at least one row"));
+        }
+
+        let mut result = self.clone();
+        result
+            .elements
+            .drain(row * self.cols_number..(row + 1) * self.cols_number);
+        result.rows_number -= 1;
+
+        Ok(result)
+    }
+
+    /// Returns a copy of the matrix without the given column.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the column index is out of bounds, or if the matrix has only one column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 2, 3;
+    ///     4, 5, 6;
+    /// ];
+    ///
+    /// assert!(m.delete_col(0).unwrap().epsilon_equals(&matrix![
+    ///     2, 3;
+    ///     5, 6;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::insert_col`]
+    /// * [`Matrix::delete_row`]
+    pub fn delete_col(&self, col: usize) -> Result<Self> {
+        self.assert_index(0, col)?;
+
+        if self.cols_number == 1 {
+            return Err(Error::InvalidOperation(
+                "Matrix must have at least one column",
+            ));
+        }
+
+        Ok(Self {
+            cols_number: self.cols_number - 1,
+            rows_number: self.rows_number,
+            elements: self
+                .elements
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| i % self.cols_number != col)
+                .map(|(_, x)| *x)
+                .collect(),
+        })
+    }
+
+    /// Returns a copy of the matrix with the given row inserted at index `at`.
+    ///
+    /// Inserting at `rows_number` appends the row at the bottom.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if `at` is greater than the number of rows, or if the length of the row is
+    /// different from the number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let m = matrix![
+    ///     1, 2;
+    ///     5, 6;
+    /// ];
+    ///
+    /// assert!(m.insert_row(1, &vector![3, 4]).unwrap().epsilon_equals(&matrix![
+    ///     1, 2;
+    ///     3, 4;
+    ///     5, 6;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::delete_row`]
+    /// * [`Matrix::insert_col`]
+    pub fn insert_row(&self, at: usize, v: &Vector) -> Result<Self> {
+        if at > self.rows_number {
+            return Err(Error::IndexOutOfBounds("Row index out of bounds"));
+        }
+
+        if v.len() != self.cols_number {
+            return Err(Error::InvalidOperation(
+                "Row length must be equal to the number of columns",
+            ));
+        }
+
+        let mut result = self.clone();
+        let start = at * self.cols_number;
+        result.elements.splice(start..start, v.data.iter().copied());
+        result.rows_number += 1;
+
+        Ok(result)
+    }
+
+    /// Returns a copy of the matrix with the given column inserted at index `at`.
+    ///
+    /// Inserting at `cols_number` appends the column on the right.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if `at` is greater than the number of columns, or if the length of the
+    /// column is different from the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let m = matrix![
+    ///     1, 2;
+    ///     3, 4;
+    /// ];
+    ///
+    /// assert!(m.insert_col(2, &vector![0, 0]).unwrap().epsilon_equals(&matrix![
+    ///     1, 2, 0;
+    ///     3, 4, 0;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::delete_col`]
+    /// * [`Matrix::insert_row`]
+    pub fn insert_col(&self, at: usize, v: &Vector) -> Result<Self> {
+        if at > self.cols_number {
+            return Err(Error::IndexOutOfBounds("Column index out of bounds"));
+        }
+
+        if v.len() != self.rows_number {
+            return Err(Error::InvalidOperation(
+                "Column length must be equal to the number of rows",
+            ));
+        }
+
+        let mut elements = Vec::with_capacity((self.cols_number + 1) * self.rows_number);
+
+        // slice the rows by index, since `chunks` panics on matrices without columns
+        for (i, value) in v.data.iter().enumerate() {
+            let row = &self.elements[i * self.cols_number..(i + 1) * self.cols_number];
+
+            elements.extend_from_slice(&row[..at]);
+            elements.push(*value);
+            elements.extend_from_slice(&row[at..]);
+        }
+
+        Ok(Self {
+            cols_number: self.cols_number + 1,
+            rows_number: self.rows_number,
+            elements,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, vector, Matrix, MatrixElement, Vector};
+
+    #[test]
+    fn delete_then_insert() {
+        let m = matrix![
+            1, 2, 3;
+            4, 5, 6;
+            7, 8, 9;
+        ];
+
+        for i in 0..3 {
+            let row = m.get_row(i).unwrap();
+            let restored = m.delete_row(i).unwrap().insert_row(i, &row).unwrap();
+            assert!(restored.epsilon_equals(&m));
+
+            let col = m.get_col(i).unwrap();
+            let restored = m.delete_col(i).unwrap().insert_col(i, &col).unwrap();
+            assert!(restored.epsilon_equals(&m));
+        }
+
+        // a matrix without columns still has rows to insert into
+        assert!(Matrix::zero(2, 0)
+            .insert_col(0, &vector![1, 2])
+            .unwrap()
+            .epsilon_equals(&matrix![1; 2]));
+    }
+
+    #[test]
+    fn delete_insert_invalid() {
+        let m = matrix![
+            1, 2;
+            3, 4;
+        ];
+
+        m.delete_row(2).unwrap_err();
+        m.delete_col(2).unwrap_err();
+        m.insert_row(3, &vector![1, 2]).unwrap_err();
+        m.insert_row(0, &vector![1, 2, 3]).unwrap_err();
+        m.insert_col(3, &vector![1, 2]).unwrap_err();
+        m.insert_col(0, &vector![1]).unwrap_err();
+
+        matrix![1, 2].delete_row(0).unwrap_err();
+        matrix![1; 2].delete_col(0).unwrap_err();
+    }
+}
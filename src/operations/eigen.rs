@@ -0,0 +1,678 @@
+use crate::{Error, Matrix, MatrixElement, Result, Vector};
+
+/// The number of power iterations used by [`Matrix::spectral_radius`].
+const SPECTRAL_RADIUS_ITERATIONS: usize = 1000;
+
+/// The largest residual `‖A v - λ v‖`, relative to the Frobenius norm of `A`, which
+/// [`Matrix::top_k_eigenpairs`] accepts as an eigenpair.
+const EIGENPAIR_TOLERANCE: f64 = 1e-6;
+
+impl Matrix {
+    /// Returns the dominant eigenvalue and its normalized eigenvector by power iteration.
+    ///
+    /// The iteration starts from the vector whose components are all `1`.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square, or if the iteration collapses to the zero vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     6, 2;
+    ///     2, 3;
+    /// ];
+    ///
+    /// let (value, vector) = m.power_iteration(100).unwrap();
+    ///
+    /// assert!(value.epsilon_equals(&7));
+    /// assert!(m.multiply_vector(&vector).unwrap().epsilon_equals(&vector.scale(value)));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Power iteration](https://en.wikipedia.org/wiki/Power_iteration)
+    /// * [`Matrix::deflate`]
+    pub fn power_iteration(&self, iterations: usize) -> Result<(MatrixElement, Vector)> {
+        self.assert_square("Only square matrices have eigenvalues")?;
+
+        let start = Vector::new(vec![MatrixElement::one(); self.rows_number]);
+
+        self._power_iteration_from(start, iterations)
+    }
+
+    /// Runs power iteration from a nonzero `start` vector.
+    fn _power_iteration_from(
+        &self,
+        start: Vector,
+        iterations: usize,
+    ) -> Result<(MatrixElement, Vector)> {
+        let mut vector = start.scale(MatrixElement::new(1.0 / start.norm().value()));
+
+        for _ in 0..iterations {
+            let next = self.multiply_vector(&vector)?;
+            let norm = next.norm();
+
+            if norm.is_zero() {
+                return Err(Error::InvalidOperation(
+                    "Power iteration collapsed to the zero vector",
+                ));
+            }
+
+            vector = next.scale(norm.inverse());
+        }
+
+        // the Rayleigh quotient of a normalized vector
+        let value = vector.dot(&self.multiply_vector(&vector)?);
+
+        Ok((value, vector))
+    }
+
+    /// Returns the eigenvalue nearest to `shift` and its normalized eigenvector by inverse
+    /// iteration.
+    ///
+    /// Each step solves `(self - shift * I) * y == x` with a single LU factorization and
+    /// normalizes `y`. With `shift = 0` it finds the eigenvalue with the smallest absolute value.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square, or if `self - shift * I` is singular, which
+    /// happens when `shift` is exactly an eigenvalue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     6, 2;
+    ///     2, 3;
+    /// ];
+    ///
+    /// let (value, vector) = m.inverse_iteration(MatrixElement::zero(), 50).unwrap();
+    ///
+    /// assert!(value.epsilon_equals(&2));
+    /// assert!(m.multiply_vector(&vector).unwrap().epsilon_equals(&vector.scale(value)));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Inverse iteration](https://en.wikipedia.org/wiki/Inverse_iteration)
+    /// * [`Matrix::power_iteration`]
+    /// * [`Matrix::lu_factor`]
+    pub fn inverse_iteration(
+        &self,
+        shift: MatrixElement,
+        iterations: usize,
+    ) -> Result<(MatrixElement, Vector)> {
+        self.assert_square("Only square matrices have eigenvalues")?;
+
+        let n = self.rows_number;
+
+        let shifted = self.add_s(&Self::identity(n).scale(shift.negate()))?;
+        let lu = shifted.lu_factor().map_err(|_| {
+            Error::InvalidOperation("The shift must not be an eigenvalue of the matrix")
+        })?;
+
+        let mut vector = Vector::new(vec![MatrixElement::one(); n]);
+        vector = vector.scale(vector.norm().inverse());
+
+        for _ in 0..iterations {
+            let next = lu.solve(&vector)?;
+
+            vector = next.scale(next.norm().inverse());
+        }
+
+        // the Rayleigh quotient of a normalized vector
+        let value = vector.dot(&self.multiply_vector(&vector)?);
+
+        Ok((value, vector))
+    }
+
+    /// Returns the Rayleigh quotient `(vᵀ A v) / (vᵀ v)` of the matrix and a vector.
+    ///
+    /// If `v` is an eigenvector, the Rayleigh quotient is its eigenvalue, and otherwise it is the
+    /// best eigenvalue estimate for `v` in the least-squares sense.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square, if the length of the vector is different from
+    /// the size of the matrix, or if the vector is the zero vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let m = matrix![
+    ///     6, 2;
+    ///     2, 3;
+    /// ];
+    ///
+    /// assert!(m.rayleigh_quotient(&vector![2, 1]).unwrap().epsilon_equals(&7));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Rayleigh quotient](https://en.wikipedia.org/wiki/Rayleigh_quotient)
+    /// * [`Matrix::power_iteration`]
+    pub fn rayleigh_quotient(&self, v: &Vector) -> Result<MatrixElement> {
+        self.assert_square("Only square matrices have eigenvalues")?;
+
+        if v.len() != self.rows_number {
+            return Err(Error::InvalidOperation(
+                "Vector length must be equal to matrix size to compute the Rayleigh quotient",
+            ));
+        }
+
+        if v.is_zero() {
+            return Err(Error::InvalidOperation(
+                "The Rayleigh quotient of the zero vector is undefined",
+            ));
+        }
+
+        Ok(v.dot(&self.multiply_vector(v)?) / v.dot(v))
+    }
+
+    /// Returns an eigenpair of the matrix by Rayleigh quotient iteration, starting from the vector
+    /// `initial`.
+    ///
+    /// It is inverse iteration whose shift is updated to the Rayleigh quotient of the current
+    /// vector in every step, which converges cubically for symmetric matrices. The iteration
+    /// stops early once the shifted matrix becomes singular, since the shift is then an eigenvalue
+    /// up to rounding errors.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square, if the length of `initial` is different from
+    /// the size of the matrix, or if `initial` is the zero vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let m = matrix![
+    ///     6, 2;
+    ///     2, 3;
+    /// ];
+    ///
+    /// let (value, vector) = m.rayleigh_quotient_iteration(&vector![1, 0], 10).unwrap();
+    ///
+    /// assert!(value.epsilon_equals(&7));
+    /// assert!(m.multiply_vector(&vector).unwrap().epsilon_equals(&vector.scale(value)));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Rayleigh quotient iteration](https://en.wikipedia.org/wiki/Rayleigh_quotient_iteration)
+    /// * [`Matrix::inverse_iteration`]
+    /// * [`Matrix::rayleigh_quotient`]
+    pub fn rayleigh_quotient_iteration(
+        &self,
+        initial: &Vector,
+        iterations: usize,
+    ) -> Result<(MatrixElement, Vector)> {
+        let mut value = self.rayleigh_quotient(initial)?;
+        let mut vector = initial.scale(initial.norm().inverse());
+
+        let n = self.rows_number;
+
+        for _ in 0..iterations {
+            let shifted = self.add_s(&Self::identity(n).scale(value.negate()))?;
+
+            // a singular shifted matrix means that the shift is already an eigenvalue
+            let Ok(lu) = shifted.lu_factor() else {
+                break;
+            };
+
+            let next = lu.solve(&vector)?;
+            let norm = next.norm();
+            if !norm.value().is_finite() || norm.is_zero() {
+                break;
+            }
+
+            vector = next.scale(norm.inverse());
+            value = self.rayleigh_quotient(&vector)?;
+        }
+
+        Ok((value, vector))
+    }
+
+    /// Returns the matrix `self - eigenvalue * v * vᵀ` which removes the given eigenpair
+    /// (Hotelling deflation).
+    ///
+    /// NOTE: The eigenvector is assumed to be normalized, and the result only keeps the other
+    ///       eigenvalues for symmetric matrices.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square or if the length of the eigenvector is
+    /// different from the size of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     6, 2;
+    ///     2, 3;
+    /// ];
+    ///
+    /// let (value, vector) = m.power_iteration(100).unwrap();
+    /// let (next, _) = m.deflate(value, &vector).unwrap().power_iteration(100).unwrap();
+    ///
+    /// assert!(next.epsilon_equals(&2));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Deflation](https://en.wikipedia.org/wiki/Eigenvalue_algorithm#Deflation)
+    /// * [`Matrix::power_iteration`]
+    pub fn deflate(&self, eigenvalue: MatrixElement, eigenvector: &Vector) -> Result<Self> {
+        self.assert_square("Only square matrices have eigenvalues")?;
+
+        if eigenvector.len() != self.rows_number {
+            return Err(Error::InvalidOperation(
+                "Eigenvector length must be equal to matrix size to deflate",
+            ));
+        }
+
+        self.add_s(&eigenvector.outer(eigenvector).scale(eigenvalue.negate()))
+    }
+
+    /// Returns the `k` eigenvalues of a symmetric matrix with the largest absolute values, in
+    /// descending order of magnitude, together with their normalized eigenvectors.
+    ///
+    /// Each eigenpair is found by power iteration, and then removed with Hotelling deflation
+    /// before looking for the next one. Power iteration is restarted from a column of the deflated
+    /// matrix if the all-ones start vector does not lead to an eigenpair.
+    ///
+    /// NOTE: Power iteration cannot separate two eigenvalues `λ` and `-λ` which share the largest
+    ///       absolute value, since it oscillates between their eigenvectors. Such matrices may
+    ///       give an error instead of the eigenpairs.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square or not symmetric, if `k` exceeds the size of the
+    /// matrix, or if power iteration does not converge to an eigenpair, which is checked through
+    /// the residual `‖A v - λ v‖`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     5, 0, 0;
+    ///     0, 1, 0;
+    ///     0, 0, -3;
+    /// ];
+    ///
+    /// let pairs = m.top_k_eigenpairs(2, 200).unwrap();
+    ///
+    /// assert!(pairs[0].0.epsilon_equals(&5));
+    /// assert!(pairs[1].0.epsilon_equals(&-3));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::power_iteration`]
+    /// * [`Matrix::deflate`]
+    pub fn top_k_eigenpairs(
+        &self,
+        k: usize,
+        iterations: usize,
+    ) -> Result<Vec<(MatrixElement, Vector)>> {
+        self.assert_square("Only square matrices have eigenvalues")?;
+
+        if !self.is_symmetric() {
+            return Err(Error::InvalidOperation("The matrix must be symmetric"));
+        }
+
+        if k > self.rows_number {
+            return Err(Error::InvalidOperation(
+                "Cannot find more eigenpairs than the size of the matrix",
+            ));
+        }
+
+        let n = self.rows_number;
+        let tolerance = EIGENPAIR_TOLERANCE * self.frobenius_norm().value();
+
+        let mut pairs = Vec::with_capacity(k);
+        let mut deflated = self.clone();
+
+        for _ in 0..k {
+            let start = Vector::new(vec![MatrixElement::one(); n]);
+            let mut pair = deflated._converged_power_iteration(start, iterations, tolerance)?;
+
+            // the start vector may be orthogonal to all remaining eigenvectors, while the largest
+            // column of the deflated matrix lies in its range
+            if pair.is_none() {
+                // INFO: safe to unwrap, since the matrix is square and not empty
+                let column = deflated
+                    .as_cols()
+                    .into_iter()
+                    .max_by(|a, b| a.norm().value().total_cmp(&b.norm().value()))
+                    .unwrap();
+
+                pair = deflated._converged_power_iteration(column, iterations, tolerance)?;
+            }
+
+            let Some((value, vector)) = pair else {
+                return Err(Error::InvalidOperation(
+                    "Power iteration did not converge to an eigenpair",
+                ));
+            };
+
+            deflated = deflated.deflate(value, &vector)?;
+            pairs.push((value, vector));
+        }
+
+        Ok(pairs)
+    }
+
+    /// Runs power iteration from `start`, returning `None` if it collapses to the zero vector or
+    /// if the residual `‖A v - λ v‖` of the result exceeds `tolerance`.
+    fn _converged_power_iteration(
+        &self,
+        start: Vector,
+        iterations: usize,
+        tolerance: f64,
+    ) -> Result<Option<(MatrixElement, Vector)>> {
+        if start.inf_norm().value() == 0.0 {
+            return Ok(None);
+        }
+
+        let Ok((value, vector)) = self._power_iteration_from(start, iterations) else {
+            return Ok(None);
+        };
+
+        let residual = self
+            .multiply_vector(&vector)?
+            .subtract(&vector.scale(value))
+            .norm();
+
+        Ok((residual.value() <= tolerance).then_some((value, vector)))
+    }
+
+    /// Returns the spectral radius of the matrix, which is the largest absolute value of its
+    /// eigenvalues.
+    ///
+    /// Symmetric matrices use the Jacobi eigenvalues, other matrices use power iteration.
+    ///
+    /// NOTE: For non-symmetric matrices the result is only accurate if there is a single dominant
+    ///       eigenvalue.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square, or if power iteration collapses to the zero
+    /// vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 4;
+    ///     1, 1;
+    /// ];
+    ///
+    /// assert!(m.spectral_radius().unwrap().epsilon_equals(&3));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Spectral radius](https://en.wikipedia.org/wiki/Spectral_radius)
+    /// * [`Matrix::power_iteration`]
+    /// * [`Matrix::eigenvalues_symmetric`]
+    pub fn spectral_radius(&self) -> Result<MatrixElement> {
+        self.assert_square("Only square matrices have eigenvalues")?;
+
+        if self.is_symmetric() {
+            return Ok(self
+                .eigenvalues_symmetric()?
+                .into_iter()
+                .map(|value| value.abs())
+                .fold(
+                    MatrixElement::zero(),
+                    |acc, x| if x > acc { x } else { acc },
+                ));
+        }
+
+        let (value, _) = self.power_iteration(SPECTRAL_RADIUS_ITERATIONS)?;
+
+        Ok(value.abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, vector};
+
+    #[test]
+    fn power_iteration() {
+        let m = matrix![
+            4, 1, 0;
+            1, 3, 1;
+            0, 1, 2;
+        ];
+
+        let (value, vector) = m.power_iteration(200).unwrap();
+
+        assert!(vector.norm().is_one());
+        assert!(m
+            .multiply_vector(&vector)
+            .unwrap()
+            .epsilon_equals(&vector.scale(value)));
+
+        matrix![0, 0; 0, 0].power_iteration(10).unwrap_err();
+        matrix![1, 2, 3; 4, 5, 6].power_iteration(10).unwrap_err();
+    }
+
+    #[test]
+    fn inverse_iteration() {
+        // eigenvalues 1, 3 and 4
+        let m = matrix![
+            2, 1, 0;
+            1, 2, 0;
+            0, 0, 4;
+        ];
+
+        for (shift, expected) in [(0.0, 1.0), (2.4, 3.0), (3.8, 4.0)] {
+            let (value, vector) = m.inverse_iteration(shift.into(), 100).unwrap();
+
+            assert!(value.epsilon_equals(&expected));
+            assert!(m
+                .multiply_vector(&vector)
+                .unwrap()
+                .epsilon_equals(&vector.scale(value)));
+        }
+
+        m.inverse_iteration(MatrixElement::new(4.0), 10)
+            .unwrap_err();
+        matrix![1, 2, 3]
+            .inverse_iteration(MatrixElement::zero(), 10)
+            .unwrap_err();
+    }
+
+    #[test]
+    fn rayleigh_quotient() {
+        let m = matrix![
+            2, 1, 0;
+            1, 2, 0;
+            0, 0, 4;
+        ];
+
+        assert!(m
+            .rayleigh_quotient(&vector![1, 1, 0])
+            .unwrap()
+            .epsilon_equals(&3));
+        assert!(m
+            .rayleigh_quotient(&vector![-2, 2, 0])
+            .unwrap()
+            .epsilon_equals(&1));
+        assert!(m
+            .rayleigh_quotient(&vector![0, 0, 0.1])
+            .unwrap()
+            .epsilon_equals(&4));
+
+        let (value, vector) = m.power_iteration(200).unwrap();
+        assert!(m.rayleigh_quotient(&vector).unwrap().epsilon_equals(&value));
+
+        m.rayleigh_quotient(&vector![0, 0, 0]).unwrap_err();
+        m.rayleigh_quotient(&vector![1, 1]).unwrap_err();
+    }
+
+    #[test]
+    fn rayleigh_quotient_iteration() {
+        // eigenvalues 1, 3 and 4
+        let m = matrix![
+            2, 1, 0;
+            1, 2, 0;
+            0, 0, 4;
+        ];
+
+        // a few steps suffice from a rough guess of each eigenvector
+        for (initial, expected) in [
+            (vector![1, 0.8, 0.1], 3.0),
+            (vector![1, -0.7, 0.1], 1.0),
+            (vector![0.1, 0, 1], 4.0),
+        ] {
+            let (value, vector) = m.rayleigh_quotient_iteration(&initial, 5).unwrap();
+
+            assert!(value.epsilon_equals(&expected));
+            assert!(vector.norm().is_one());
+
+            // the iteration stops once the shift makes the pivots negligible, so the vector is
+            // less accurate than its Rayleigh quotient
+            let residual = m
+                .multiply_vector(&vector)
+                .unwrap()
+                .subtract(&vector.scale(value))
+                .norm();
+            assert!(residual.value() < 1e-3);
+        }
+
+        // starting from an exact eigenvector stops without an error
+        let (value, _) = m.rayleigh_quotient_iteration(&vector![0, 0, 1], 5).unwrap();
+        assert!(value.epsilon_equals(&4));
+
+        m.rayleigh_quotient_iteration(&vector![0, 0, 0], 5)
+            .unwrap_err();
+    }
+
+    #[test]
+    fn deflate() {
+        let m = matrix![
+            5, 0, 0;
+            0, 2, 0;
+            0, 0, 1;
+        ];
+
+        let (first, vector) = m.power_iteration(100).unwrap();
+        assert!(first.epsilon_equals(&5));
+
+        let deflated = m.deflate(first, &vector).unwrap();
+        let (second, vector) = deflated.power_iteration(100).unwrap();
+        assert!(second.epsilon_equals(&2));
+
+        let deflated = deflated.deflate(second, &vector).unwrap();
+        let (third, _) = deflated.power_iteration(100).unwrap();
+        assert!(third.epsilon_equals(&1));
+
+        m.deflate(first, &vector![1, 0]).unwrap_err();
+    }
+
+    #[test]
+    fn spectral_radius() {
+        // eigenvalues 3 and -1
+        let symmetric = matrix![
+            1, 2;
+            2, 1;
+        ];
+        assert!(symmetric.spectral_radius().unwrap().epsilon_equals(&3));
+
+        let general = matrix![
+            1, 4;
+            1, 1;
+        ];
+        assert!(general.spectral_radius().unwrap().epsilon_equals(&3));
+
+        // eigenvalues -3 and 1
+        assert!(symmetric
+            .scale(-1)
+            .spectral_radius()
+            .unwrap()
+            .epsilon_equals(&3));
+
+        matrix![1, 2, 3].spectral_radius().unwrap_err();
+    }
+
+    #[test]
+    fn top_k_eigenpairs() {
+        // eigenvalues 3, -1 and -4
+        let m = matrix![
+            1, 2, 0;
+            2, 1, 0;
+            0, 0, -4;
+        ];
+
+        let pairs = m.top_k_eigenpairs(2, 300).unwrap();
+
+        assert_eq!(pairs.len(), 2);
+        assert!(pairs[0].0.epsilon_equals(&-4));
+        assert!(pairs[1].0.epsilon_equals(&3));
+
+        for (value, vector) in pairs {
+            assert!(m
+                .multiply_vector(&vector)
+                .unwrap()
+                .epsilon_equals(&vector.scale(value)));
+        }
+
+        m.top_k_eigenpairs(4, 100).unwrap_err();
+        matrix![1, 2; 0, 1].top_k_eigenpairs(1, 100).unwrap_err();
+    }
+
+    #[test]
+    fn top_k_eigenpairs_opposite_eigenvalues() {
+        for (m, magnitude) in [
+            (
+                matrix![
+                    2, 0, 0;
+                    0, -2, 0;
+                    0, 0, 1;
+                ],
+                2,
+            ),
+            (
+                matrix![
+                    0, 1;
+                    1, 0;
+                ],
+                1,
+            ),
+        ] {
+            let pairs = m.top_k_eigenpairs(2, 200).unwrap();
+
+            // the order of `λ` and `-λ` is not specified
+            assert!((pairs[0].0 + pairs[1].0).is_zero());
+
+            for (value, vector) in pairs {
+                assert!(value.abs().epsilon_equals(&magnitude));
+                assert!(m
+                    .multiply_vector(&vector)
+                    .unwrap()
+                    .epsilon_equals(&vector.scale(value)));
+            }
+        }
+
+        // eigenvalues 2 and -2, where neither the all-ones vector nor a column is an eigenvector
+        matrix![
+            1.2, 1.6;
+            1.6, -1.2;
+        ]
+        .top_k_eigenpairs(1, 200)
+        .unwrap_err();
+    }
+}
@@ -0,0 +1,245 @@
+use crate::{Error, Matrix, MatrixElement, Result};
+
+/// An exact fraction `num / den` with `den > 0` and `gcd(num, den) == 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    /// Returns the normalized fraction, or `None` if the denominator is zero or it overflows.
+    fn new(num: i128, den: i128) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+
+        let gcd = Self::_gcd(num.checked_abs()?, den.checked_abs()?).max(1);
+        let sign = den.signum();
+
+        Some(Rational {
+            num: sign.checked_mul(num / gcd)?,
+            den: sign.checked_mul(den / gcd)?,
+        })
+    }
+
+    /// Returns the exact value of a finite float, or `None` if it does not fit.
+    fn from_f64(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+
+        // every finite float is an integer divided by a power of two
+        let mut num = value;
+        let mut den: i128 = 1;
+        while num.fract() != 0.0 {
+            num *= 2.0;
+            den = den.checked_mul(2)?;
+        }
+
+        if num.abs() >= i128::MAX as f64 {
+            return None;
+        }
+
+        Self::new(num as i128, den)
+    }
+
+    fn to_f64(self) -> f64 {
+        self.num as f64 / self.den as f64
+    }
+
+    fn is_zero(self) -> bool {
+        self.num == 0
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        Self::new(
+            self.num
+                .checked_mul(other.den)?
+                .checked_sub(other.num.checked_mul(self.den)?)?,
+            self.den.checked_mul(other.den)?,
+        )
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        Self::new(
+            self.num.checked_mul(other.num)?,
+            self.den.checked_mul(other.den)?,
+        )
+    }
+
+    fn checked_div(self, other: Self) -> Option<Self> {
+        Self::new(
+            self.num.checked_mul(other.den)?,
+            self.den.checked_mul(other.num)?,
+        )
+    }
+
+    fn _gcd(mut a: i128, mut b: i128) -> i128 {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+
+        a
+    }
+}
+
+impl Matrix {
+    /// Returns the reduced row echelon form of the matrix, computed in exact rational arithmetic.
+    ///
+    /// Every element is converted to the fraction it represents exactly, and the elimination tests
+    /// for exact zeros instead of comparing within epsilon. The result is converted back at the
+    /// end, so integer matrices reduce without any floating-point noise.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if an element is not finite, or if a numerator or denominator overflows
+    /// during the elimination.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     3, 1;
+    ///     0, 0;
+    /// ];
+    ///
+    /// let rref = m.rref_exact().unwrap();
+    ///
+    /// assert_eq!(rref.get(0, 1).unwrap().value(), 1.0 / 3.0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Rational number](https://en.wikipedia.org/wiki/Rational_number)
+    /// * [`Matrix::to_rref`]
+    pub fn rref_exact(&self) -> Result<Self> {
+        let overflow = || Error::InvalidOperation("Exact elimination overflowed");
+
+        let m = self.rows_number;
+        let n = self.cols_number;
+
+        let mut rows = self
+            .as_rows()
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|element| Rational::from_f64(element.value()))
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or(Error::InvalidOperation(
+                "The matrix cannot be represented exactly as rationals",
+            ))?;
+
+        let mut i = 0;
+        for j in 0..n {
+            if i == m {
+                break;
+            }
+
+            let Some(pivot_row) = (i..m).find(|&k| !rows[k][j].is_zero()) else {
+                continue;
+            };
+            rows.swap(i, pivot_row);
+
+            // normalize the leading entry of row_i
+            let pivot = rows[i][j];
+            for element in rows[i].iter_mut() {
+                *element = element.checked_div(pivot).ok_or_else(overflow)?;
+            }
+
+            // eliminate all other entries in the pivot column
+            for k in (0..m).filter(|&k| k != i) {
+                let factor = rows[k][j];
+                if factor.is_zero() {
+                    continue;
+                }
+
+                let pivot_row = rows[i].clone();
+                for (element, pivot_element) in rows[k].iter_mut().zip(pivot_row) {
+                    let step = pivot_element.checked_mul(factor).ok_or_else(overflow)?;
+                    *element = element.checked_sub(step).ok_or_else(overflow)?;
+                }
+            }
+
+            i += 1;
+        }
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|element| MatrixElement::new(element.to_f64()))
+                    .collect::<Vec<_>>()
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    fn assert_exactly_equals(a: &Matrix, b: &Matrix) {
+        assert_eq!(
+            (a.rows_number, a.cols_number),
+            (b.rows_number, b.cols_number)
+        );
+
+        for (x, y) in a
+            .as_rows()
+            .into_iter()
+            .flatten()
+            .zip(b.as_rows().into_iter().flatten())
+        {
+            assert_eq!(x.value().to_bits(), y.value().to_bits());
+        }
+    }
+
+    #[test]
+    fn rref_exact() {
+        let m = matrix![
+            0, -3, -6, 4, 9;
+            -1, -2, -1, 3, 1;
+            -2, -3, 0, 3, -1;
+            1, 4, 5, -9, -7;
+        ];
+
+        assert_exactly_equals(
+            &m.rref_exact().unwrap(),
+            &matrix![
+                1, 0, -3, 0, 5;
+                0, 1, 2, 0, -3;
+                0, 0, 0, 1, 0;
+                0, 0, 0, 0, 0;
+            ],
+        );
+    }
+
+    #[test]
+    fn rref_exact_fractions() {
+        // [ A | I ] -> [ I | A⁻¹ ], and det(A) = 5
+        let augmented = matrix![
+            2, 1, 1, 0;
+            1, 3, 0, 1;
+        ];
+
+        let fifth = |x: f64| x / 5.0;
+        assert_exactly_equals(
+            &augmented.rref_exact().unwrap(),
+            &matrix![
+                1, 0, fifth(3.0), fifth(-1.0);
+                0, 1, fifth(-1.0), fifth(2.0);
+            ],
+        );
+
+        let thirds = matrix![3, 1, 2].rref_exact().unwrap();
+        assert_eq!(thirds[(0, 1)].value().to_bits(), (1.0f64 / 3.0).to_bits());
+        assert_eq!(thirds[(0, 2)].value().to_bits(), (2.0f64 / 3.0).to_bits());
+
+        matrix![f64::NAN, 1].rref_exact().unwrap_err();
+    }
+}
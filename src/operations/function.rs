@@ -0,0 +1,90 @@
+use crate::{Matrix, MatrixElement, Result};
+
+impl Matrix {
+    /// Applies a function to a symmetric matrix through its eigenvalues.
+    ///
+    /// The matrix is diagonalized as `A = V * D * Vᵀ`, and the result is `V * f(D) * Vᵀ`, where
+    /// `f` is applied to each eigenvalue. For example, `f64::exp` gives the matrix exponential and
+    /// `f64::sqrt` gives the square root of a positive semi-definite matrix.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square or not symmetric.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     5, 4;
+    ///     4, 5;
+    /// ];
+    ///
+    /// let root = m.apply_function_symmetric(f64::sqrt).unwrap();
+    ///
+    /// assert!(root.epsilon_equals(&matrix![
+    ///     2, 1;
+    ///     1, 2;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Matrix function](https://en.wikipedia.org/wiki/Matrix_function#Diagonalizable_matrices)
+    /// * [`Matrix::diagonalize`]
+    pub fn apply_function_symmetric(&self, f: impl Fn(f64) -> f64) -> Result<Self> {
+        let (d, v) = self.diagonalize()?;
+
+        let values = d
+            .diagonal_iter()
+            .map(|x| MatrixElement::new(f(x.value())))
+            .collect();
+
+        // V * f(D) scales the columns of V
+        v.scale_cols_by(&values)?.multiply(&v.transpose())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, Matrix, MatrixElement};
+
+    #[test]
+    fn apply_function_symmetric_exp() {
+        // exp([[a, b], [b, a]]) = e^a * [[cosh b, sinh b], [sinh b, cosh b]]
+        let (a, b) = (0.5_f64, 1.5_f64);
+        let m = matrix![
+            a, b;
+            b, a;
+        ];
+
+        let exp = m.apply_function_symmetric(f64::exp).unwrap();
+
+        assert!(exp.epsilon_equals(&matrix![
+            a.exp() * b.cosh(), a.exp() * b.sinh();
+            a.exp() * b.sinh(), a.exp() * b.cosh();
+        ]));
+    }
+
+    #[test]
+    fn apply_function_symmetric_identity() {
+        let m = matrix![
+            4, 1, -2;
+            1, 3, 0;
+            -2, 0, 5;
+        ];
+
+        assert!(m
+            .apply_function_symmetric(|x| x)
+            .unwrap()
+            .epsilon_equals(&m));
+        assert!(m
+            .apply_function_symmetric(|x| x * x)
+            .unwrap()
+            .epsilon_equals(&(m.clone() * m)));
+
+        matrix![1, 2; 3, 4]
+            .apply_function_symmetric(f64::exp)
+            .unwrap_err();
+    }
+}
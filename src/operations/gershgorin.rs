@@ -0,0 +1,141 @@
+use crate::{Matrix, MatrixElement, Result};
+
+impl Matrix {
+    /// Returns the Gershgorin discs of the matrix as `(center, radius)` pairs, one for each row.
+    ///
+    /// The center is the diagonal element of the row and the radius is the sum of the absolute
+    /// values of its off-diagonal elements. Every eigenvalue lies in the union of the discs, so
+    /// the matrix is non-singular if no disc contains zero.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     10, -1;
+    ///     2, 5;
+    /// ];
+    ///
+    /// let discs = m.gershgorin_discs().unwrap();
+    ///
+    /// assert!(discs[0].0.epsilon_equals(&10) && discs[0].1.epsilon_equals(&1));
+    /// assert!(discs[1].0.epsilon_equals(&5) && discs[1].1.epsilon_equals(&2));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Gershgorin circle theorem](https://en.wikipedia.org/wiki/Gershgorin_circle_theorem)
+    /// * [`Matrix::diagonal_dominance_ratio`]
+    pub fn gershgorin_discs(&self) -> Result<Vec<(MatrixElement, MatrixElement)>> {
+        self.assert_square("Only square matrices have Gershgorin discs")?;
+
+        Ok(self
+            .as_rows()
+            .into_iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let radius = row
+                    .data
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != i)
+                    .fold(MatrixElement::zero(), |acc, (_, x)| acc + x.abs());
+
+                (row[i], radius)
+            })
+            .collect())
+    }
+
+    /// Returns an upper bound on the spectral radius of the matrix, which is the largest value of
+    /// `|center| + radius` over all Gershgorin discs.
+    ///
+    /// It is much cheaper than [`Matrix::spectral_radius`], since no eigenvalues are computed.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     0.5, 0.25;
+    ///     -0.1, 0.3;
+    /// ];
+    ///
+    /// // so the Jacobi-style iteration `x = m * x + c` converges
+    /// assert!(m.spectral_radius_bound().unwrap().epsilon_equals(&0.75));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Gershgorin circle theorem](https://en.wikipedia.org/wiki/Gershgorin_circle_theorem)
+    /// * [`Matrix::gershgorin_discs`]
+    /// * [`Matrix::spectral_radius`]
+    pub fn spectral_radius_bound(&self) -> Result<MatrixElement> {
+        Ok(self
+            .gershgorin_discs()?
+            .into_iter()
+            .map(|(center, radius)| center.abs() + radius)
+            .fold(
+                MatrixElement::zero(),
+                |acc, x| if x > acc { x } else { acc },
+            ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, Matrix, MatrixElement};
+
+    #[test]
+    fn gershgorin_discs() {
+        let m = matrix![
+            8, 1, -2;
+            -1, -6, 3;
+            0, 2, 5;
+        ];
+
+        let discs = m.gershgorin_discs().unwrap();
+
+        assert_eq!(discs.len(), 3);
+        assert!(discs[1].0.epsilon_equals(&-6));
+        assert!(discs[1].1.epsilon_equals(&4));
+
+        // the matrix is diagonally dominant, so no disc contains zero and it is non-singular
+        for (center, radius) in &discs {
+            assert!(center.abs() > *radius);
+        }
+        assert!(!m.det().unwrap().is_zero());
+
+        // every eigenvalue lies in some disc
+        let (value, _) = m.power_iteration(500).unwrap();
+        assert!(discs
+            .iter()
+            .any(|(center, radius)| (value - *center).abs() <= *radius));
+
+        matrix![1, 2, 3].gershgorin_discs().unwrap_err();
+    }
+
+    #[test]
+    fn spectral_radius_bound() {
+        let m = matrix![
+            2, -1, 0, 3;
+            -1, -5, 2, 0;
+            0, 2, 1, 1;
+            3, 0, 1, 4;
+        ];
+
+        let bound = m.spectral_radius_bound().unwrap();
+
+        assert!(bound.epsilon_equals(&8));
+        assert!(bound >= m.spectral_radius().unwrap());
+
+        matrix![1, 2, 3].spectral_radius_bound().unwrap_err();
+    }
+}
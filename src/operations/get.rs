@@ -1,37 +1,125 @@
 use std::ops::Index;
 
-use crate::{Matrix, MatrixElement, Result, Vector};
+use crate::{Error, Matrix, MatrixElement, Result, Vector};
 
 impl Matrix {
     #[doc(hidden)]
     pub fn get(&self, row: usize, col: usize) -> Result<MatrixElement> {
         self.assert_index(row, col)?;
 
-        Ok(self.elements[row][col])
+        Ok(self.elements[row * self.cols_number + col])
+    }
+
+    /// Returns the element at the given `(row, col)` position.
+    ///
+    /// This is the fallible counterpart of indexing with `matrix[(row, col)]`, which panics when
+    /// the position is out of bounds.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the row or column index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 2;
+    ///     3, 4;
+    /// ];
+    ///
+    /// assert!(m.try_index((1, 0)).unwrap().epsilon_equals(&3));
+    /// m.try_index((2, 0)).unwrap_err();
+    /// ```
+    pub fn try_index(&self, pos: (usize, usize)) -> Result<MatrixElement> {
+        self.get(pos.0, pos.1)
     }
 
     #[doc(hidden)]
     pub fn get_row(&self, row: usize) -> Result<Vector> {
         self.assert_index(row, 0)?;
 
-        Ok(self.elements[row].clone().into())
+        let start = row * self.cols_number;
+
+        Ok(self.elements[start..start + self.cols_number]
+            .to_vec()
+            .into())
     }
 
     #[doc(hidden)]
     pub fn get_col(&self, col: usize) -> Result<Vector> {
         self.assert_index(0, col)?;
 
-        let mut column = Vec::new();
-
-        for i in 0..self.rows_number {
-            column.push(self.elements[i][col]);
-        }
-
-        Ok(column.into())
+        Ok(self
+            .elements
+            .iter()
+            .skip(col)
+            .step_by(self.cols_number)
+            .copied()
+            .collect())
     }
 }
 
 impl Matrix {
+    /// Returns the elements of the matrix as a slice, stored row by row.
+    ///
+    /// The element at `(row, col)` is at index `row * cols_number + col`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 2;
+    ///     3, 4;
+    /// ];
+    ///
+    /// assert!(m.as_slice()[2].epsilon_equals(&3));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::rows_iter`]
+    pub fn as_slice(&self) -> &[MatrixElement] {
+        &self.elements
+    }
+
+    /// Returns an iterator over the rows of the matrix as slices, without copying them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 2;
+    ///     3, 4;
+    /// ];
+    ///
+    /// let sums = m
+    ///     .rows_iter()
+    ///     .map(|row| row.iter().fold(MatrixElement::zero(), |acc, &x| acc + x))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert!(sums[0].epsilon_equals(&3));
+    /// assert!(sums[1].epsilon_equals(&7));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::as_slice`]
+    /// * [`Matrix::as_rows`]
+    pub fn rows_iter(&self) -> impl Iterator<Item = &[MatrixElement]> + '_ {
+        // slice the rows by index, since `chunks` panics on matrices without columns
+        (0..self.rows_number)
+            .map(move |i| &self.elements[i * self.cols_number..(i + 1) * self.cols_number])
+    }
+
+    /// Returns the elements of the matrix as nested rows, which is how they used to be stored.
+    #[deprecated(note = "use `Matrix::rows_iter` or `Matrix::as_slice` instead")]
+    pub fn elements(&self) -> Vec<Vec<MatrixElement>> {
+        self.rows_iter().map(|row| row.to_vec()).collect()
+    }
+
     /// Returns the matrix as a vector of rows.
     pub fn as_rows(&self) -> Vec<Vector> {
         let mut rows = Vec::new();
@@ -53,6 +141,34 @@ impl Matrix {
 
         cols
     }
+
+    /// Returns the elements of a matrix with a single row or a single column as a vector.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix has more than one row and more than one column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// assert!(matrix![1; 2; 3].to_vector().unwrap().epsilon_equals(&vector![1, 2, 3]));
+    /// assert!(matrix![1, 2, 3].to_vector().unwrap().epsilon_equals(&vector![1, 2, 3]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Vector::as_column`]
+    /// * [`Vector::as_row`]
+    pub fn to_vector(&self) -> Result<Vector> {
+        if self.rows_number != 1 && self.cols_number != 1 {
+            return Err(Error::InvalidOperation(
+                "Only matrices with a single row or column can be converted to a vector",
+            ));
+        }
+
+        Ok(self.elements.clone().into())
+    }
 }
 
 impl Index<(usize, usize)> for Matrix {
@@ -61,7 +177,12 @@ impl Index<(usize, usize)> for Matrix {
     fn index(&self, index: (usize, usize)) -> &Self::Output {
         let (row, col) = index;
 
-        &self.elements[row][col]
+        assert!(
+            row < self.rows_number && col < self.cols_number,
+            "Index out of bounds"
+        );
+
+        &self.elements[row * self.cols_number + col]
     }
 }
 
@@ -86,6 +207,23 @@ mod tests {
         assert!(m.get(2, 0).unwrap().epsilon_equals(&7.0));
     }
 
+    #[test]
+    fn try_index() {
+        let m = matrix![
+            1, 2, 3;
+            4, 5, 6;
+        ];
+
+        for i in 0..2 {
+            for j in 0..3 {
+                assert!(m.try_index((i, j)).unwrap().epsilon_equals(&m[(i, j)]));
+            }
+        }
+
+        m.try_index((2, 0)).unwrap_err();
+        m.try_index((0, 3)).unwrap_err();
+    }
+
     #[test]
     fn get_row() {
         let m = matrix![
@@ -111,4 +249,27 @@ mod tests {
         assert!(m.get_col(1).unwrap().epsilon_equals(&vector![2, 5, 8]));
         assert!(m.get_col(2).unwrap().epsilon_equals(&vector![3, 6, 9]));
     }
+
+    #[test]
+    fn to_vector() {
+        let v = vector![1, -2, 3.5];
+
+        let column = v.as_column().unwrap();
+        assert_eq!((column.rows_number, column.cols_number), (3, 1));
+        assert!(column.to_vector().unwrap().epsilon_equals(&v));
+
+        let row = v.as_row();
+        assert_eq!((row.rows_number, row.cols_number), (1, 3));
+        assert!(row.to_vector().unwrap().epsilon_equals(&v));
+
+        matrix![1, 2; 3, 4].to_vector().unwrap_err();
+
+        // a matrix may have no columns, but not no rows
+        let empty = Vector::zero(0);
+        empty.as_column().unwrap_err();
+        assert_eq!(
+            (empty.as_row().rows_number, empty.as_row().cols_number),
+            (1, 0)
+        );
+    }
 }
@@ -0,0 +1,209 @@
+use crate::{Error, Matrix, Result};
+
+impl Matrix {
+    /// Returns the Givens rotation matrix `G` of the given size for the angle `theta`.
+    ///
+    /// `G` is the identity except for `G[i, i] = cos θ`, `G[i, j] = sin θ`, `G[j, i] = -sin θ` and
+    /// `G[j, j] = cos θ`, so `G * A` is the same as applying [`Matrix::apply_givens_left`] to `A`.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if an index is out of bounds or if `i == j`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let g = Matrix::givens(3, 0, 2, std::f64::consts::FRAC_PI_2).unwrap();
+    ///
+    /// assert!(g.epsilon_equals(&matrix![
+    ///     0, 0, 1;
+    ///     0, 1, 0;
+    ///     -1, 0, 0;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Givens rotation](https://en.wikipedia.org/wiki/Givens_rotation)
+    /// * [`Matrix::apply_givens_left`]
+    pub fn givens(size: usize, i: usize, j: usize, theta: f64) -> Result<Self> {
+        let (s, c) = theta.sin_cos();
+
+        let mut matrix = Matrix::identity(size);
+        matrix.apply_givens_left(i, j, c, s)?;
+
+        Ok(matrix)
+    }
+
+    /// Applies the Givens rotation `G` to rows `i` and `j` in place, which is `self = G * self`.
+    ///
+    /// `G` is the identity except for `G[i, i] = c`, `G[i, j] = s`, `G[j, i] = -s` and `G[j, j] = c`.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the row index is out of bounds or if `i == j`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let mut m = matrix![
+    ///     3, 1;
+    ///     4, 2;
+    /// ];
+    ///
+    /// // zero out the element at (1, 0)
+    /// m.apply_givens_left(0, 1, 0.6, 0.8).unwrap();
+    ///
+    /// assert!(m.epsilon_equals(&matrix![
+    ///     5, 2.2;
+    ///     0, 0.4;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Givens rotation](https://en.wikipedia.org/wiki/Givens_rotation)
+    /// * [`Matrix::apply_givens_right`]
+    pub fn apply_givens_left(&mut self, i: usize, j: usize, c: f64, s: f64) -> Result<()> {
+        self.assert_index(i, 0)?;
+        self.assert_index(j, 0)?;
+
+        if i == j {
+            return Err(Error::InvalidOperation(
+                "Givens rotation requires two different rows",
+            ));
+        }
+
+        let row_i = self.get_row(i)?;
+        let row_j = self.get_row(j)?;
+
+        self.set_row(i, row_i.scale(c).add(&row_j.scale(s)))?;
+        self.set_row(j, row_j.scale(c).subtract(&row_i.scale(s)))?;
+
+        Ok(())
+    }
+
+    /// Applies the Givens rotation `G` to columns `i` and `j` in place, which is `self = self * G`.
+    ///
+    /// `G` is the identity except for `G[i, i] = c`, `G[i, j] = s`, `G[j, i] = -s` and `G[j, j] = c`.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the column index is out of bounds or if `i == j`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let mut m = matrix![
+    ///     1, 0;
+    ///     0, 1;
+    /// ];
+    ///
+    /// m.apply_givens_right(0, 1, 0.0, 1.0).unwrap();
+    ///
+    /// assert!(m.epsilon_equals(&matrix![
+    ///     0, 1;
+    ///     -1, 0;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Givens rotation](https://en.wikipedia.org/wiki/Givens_rotation)
+    /// * [`Matrix::apply_givens_left`]
+    pub fn apply_givens_right(&mut self, i: usize, j: usize, c: f64, s: f64) -> Result<()> {
+        self.assert_index(0, i)?;
+        self.assert_index(0, j)?;
+
+        if i == j {
+            return Err(Error::InvalidOperation(
+                "Givens rotation requires two different columns",
+            ));
+        }
+
+        let col_i = self.get_col(i)?;
+        let col_j = self.get_col(j)?;
+
+        self.set_col(i, col_i.scale(c).subtract(&col_j.scale(s)))?;
+        self.set_col(j, col_j.scale(c).add(&col_i.scale(s)))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, MatrixElement, Vector};
+
+    #[test]
+    fn apply_givens() {
+        let m = matrix![
+            1, 2, 3;
+            -4, 5, 6;
+            7, 8, -9;
+        ];
+        let (s, c) = 0.7_f64.sin_cos();
+
+        let mut rotated = m.clone();
+        rotated.apply_givens_left(0, 2, c, s).unwrap();
+        assert!(!rotated.epsilon_equals(&m));
+        assert!(rotated
+            .get_row(1)
+            .unwrap()
+            .epsilon_equals(&m.get_row(1).unwrap()));
+        rotated.apply_givens_left(0, 2, c, -s).unwrap();
+        assert!(rotated.epsilon_equals(&m));
+
+        let mut rotated = m.clone();
+        rotated.apply_givens_right(2, 1, c, s).unwrap();
+        assert!(!rotated.epsilon_equals(&m));
+        rotated.apply_givens_right(2, 1, c, -s).unwrap();
+        assert!(rotated.epsilon_equals(&m));
+    }
+
+    #[test]
+    fn givens() {
+        let theta = std::f64::consts::FRAC_PI_6;
+        let (s, c) = theta.sin_cos();
+
+        let g = Matrix::givens(4, 1, 3, theta).unwrap();
+        assert!(g.is_orthogonal());
+
+        let m = matrix![
+            1, 2, 3, 4;
+            5, 6, 7, 8;
+            9, 10, 11, 12;
+            13, 14, 15, 16;
+        ];
+        let mut rotated = m.clone();
+        rotated.apply_givens_left(1, 3, c, s).unwrap();
+        assert!((g * m).epsilon_equals(&rotated));
+
+        // with `i > j` the rotation is counterclockwise in the `(j, i)` plane
+        let g = Matrix::givens(2, 1, 0, theta).unwrap();
+        assert!(g
+            .multiply_vector(&Vector::new(vec![
+                MatrixElement::one(),
+                MatrixElement::zero()
+            ]))
+            .unwrap()
+            .epsilon_equals(&Vector::new(vec![c.into(), s.into()])));
+    }
+
+    #[test]
+    fn apply_givens_invalid() {
+        let mut m = matrix![1, 2; 3, 4];
+
+        m.apply_givens_left(0, 0, 1.0, 0.0).unwrap_err();
+        m.apply_givens_left(0, 2, 1.0, 0.0).unwrap_err();
+        m.apply_givens_right(1, 1, 1.0, 0.0).unwrap_err();
+        m.apply_givens_right(2, 1, 1.0, 0.0).unwrap_err();
+
+        Matrix::givens(2, 1, 1, 0.5).unwrap_err();
+        Matrix::givens(2, 0, 2, 0.5).unwrap_err();
+    }
+}
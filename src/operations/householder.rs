@@ -0,0 +1,131 @@
+use crate::{Error, Matrix, MatrixElement, Result, Vector};
+
+impl Matrix {
+    /// Returns the Householder matrix `I - 2 * (v vᵀ) / (vᵀ v)`, which reflects vectors across the
+    /// hyperplane orthogonal to `v`.
+    ///
+    /// The identity matrix is returned if all elements of `v` are exactly zero. Any other `v`, no
+    /// matter how small, gives a reflection.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `v` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let h = Matrix::householder(&vector![1, 1]);
+    ///
+    /// assert!(h.epsilon_equals(&matrix![
+    ///     0, -1;
+    ///     -1, 0;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Householder transformation](https://en.wikipedia.org/wiki/Householder_transformation)
+    /// * [`Vector::householder_vector`]
+    /// * [`Vector::outer`]
+    pub fn householder(v: &Vector) -> Self {
+        let identity = Matrix::identity(v.len());
+
+        let largest = v.inf_norm().value();
+        if largest == 0.0 {
+            return identity;
+        }
+
+        // the squared norm of a tiny `v` falls below the epsilon of `MatrixElement`, so `v` is
+        // scaled to have a largest component of 1, which leaves the reflection unchanged
+        let u = v.scale(MatrixElement::new(1.0 / largest));
+        let factor = MatrixElement::from(2) / u.dot(&u);
+
+        identity + u.outer(&u).scale(factor.negate())
+    }
+
+    /// Returns the matrix `I - 2 * (n nᵀ) / (nᵀ n)` which reflects vectors across the hyperplane
+    /// with the given normal.
+    ///
+    /// The result is orthogonal, symmetric and involutory.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the normal is empty, or if all of its elements are exactly zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{vector, Matrix, MatrixElement, Vector};
+    /// let r = Matrix::householder_reflection(&vector![0, 1]).unwrap();
+    ///
+    /// assert!(r.multiply_vector(&vector![3, 4]).unwrap().epsilon_equals(&vector![3, -4]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Reflection (mathematics)](https://en.wikipedia.org/wiki/Reflection_(mathematics)#Reflection_through_a_hyperplane_in_n_dimensions)
+    /// * [`Matrix::householder`]
+    pub fn householder_reflection(normal: &Vector) -> Result<Self> {
+        // only an exactly zero normal is rejected, since `householder` reflects across any other
+        if normal.is_empty() || normal.inf_norm().value() == 0.0 {
+            return Err(Error::InvalidOperation(
+                "The normal of a hyperplane must not be the zero vector",
+            ));
+        }
+
+        Ok(Self::householder(normal))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{vector, Matrix, MatrixElement, Vector};
+
+    #[test]
+    fn householder() {
+        let v = vector![1, -2, 3, 0.5];
+        let h = Matrix::householder(&v);
+
+        assert!(h.is_symmetric());
+        assert!(h.is_orthogonal());
+
+        // `v` is reflected to `-v`
+        assert!(h.multiply_vector(&v).unwrap().epsilon_equals(&v.negate()));
+    }
+
+    #[test]
+    fn householder_small() {
+        let v = vector![1e-5, -2e-5, 3e-6];
+        let h = Matrix::householder(&v);
+
+        assert!(h.is_orthogonal());
+        assert!(h.epsilon_equals(&Matrix::householder(&v.scale(MatrixElement::new(1e5)))));
+        assert!(!h.epsilon_equals(&Matrix::identity(3)));
+    }
+
+    #[test]
+    fn householder_zero() {
+        assert!(Matrix::householder(&Vector::zero(3)).epsilon_equals(&Matrix::identity(3)));
+    }
+
+    #[test]
+    fn householder_reflection() {
+        let r = Matrix::householder_reflection(&vector![0, 0, 2]).unwrap();
+
+        assert!(r.is_orthogonal());
+        assert!(r.is_symmetric());
+        assert!((r.clone() * r.clone()).epsilon_equals(&Matrix::identity(3)));
+
+        assert!(r
+            .multiply_vector(&vector![1, -2, 3])
+            .unwrap()
+            .epsilon_equals(&vector![1, -2, -3]));
+
+        // a tiny normal still gives the same reflection
+        let small = Matrix::householder_reflection(&vector![0, 0, 1e-5]).unwrap();
+        assert!(small.epsilon_equals(&r));
+
+        Matrix::householder_reflection(&Vector::zero(3)).unwrap_err();
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Error, Matrix, Result};
+use crate::{Error, Matrix, MatrixElement, Result, Vector};
 
 impl Matrix {
     /// Returns the inverse of the matrix.
@@ -40,11 +40,168 @@ impl Matrix {
         Ok(applied)
     }
 
+    /// Returns the inverse of the matrix, reporting a division by zero as an error.
+    ///
+    /// Each pivot is normalized with [`MatrixElement::checked_div`], so a singular matrix yields an
+    /// error instead of a panic anywhere in the computation.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square or cannot be inverted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     2, 0;
+    ///     0, 4;
+    /// ];
+    ///
+    /// assert!(m.try_inverse().unwrap().epsilon_equals(&matrix![
+    ///     0.5, 0;
+    ///     0, 0.25;
+    /// ]));
+    /// matrix![1, 2; 2, 4].try_inverse().unwrap_err();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::inverse`]
+    pub fn try_inverse(&self) -> Result<Self> {
+        self.assert_square("Only square matrices can be inverted")?;
+
+        // [ A | I ] -> [ I | A^(-1) ]
+        let (origin, mut applied) = self.to_rref_apply_to(Self::identity(self.rows_number))?;
+
+        // a singular matrix leaves a zero on the diagonal of its reduced row echelon form
+        for i in 0..self.rows_number {
+            let factor = MatrixElement::one().checked_div(origin.get(i, i)?)?;
+            applied.scale_row(i, factor)?;
+        }
+
+        Ok(applied)
+    }
+
+    /// Returns the inverse of `self + u * vᵀ`, given the inverse `inv` of `self`, with the
+    /// Sherman–Morrison formula.
+    ///
+    /// This takes `O(n²)` operations instead of the `O(n³)` of a fresh inversion.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrices are not square or have different sizes, if the lengths of
+    /// `u` and `v` are different from the size, or if the updated matrix cannot be inverted, which
+    /// happens when `1 + vᵀ * inv * u` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let m = Matrix::identity(2);
+    /// let updated = m
+    ///     .inverse_rank1_update(&Matrix::identity(2), &vector![1, 0], &vector![1, 0])
+    ///     .unwrap();
+    ///
+    /// assert!(updated.epsilon_equals(&matrix![
+    ///     0.5, 0;
+    ///     0, 1;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Sherman–Morrison formula](https://en.wikipedia.org/wiki/Sherman%E2%80%93Morrison_formula)
+    /// * [`Matrix::inverse`]
+    pub fn inverse_rank1_update(&self, inv: &Self, u: &Vector, v: &Vector) -> Result<Self> {
+        self.assert_square("Only square matrices can be inverted")?;
+
+        let n = self.rows_number;
+
+        if inv.rows_number != n || inv.cols_number != n {
+            return Err(Error::InvalidOperation(
+                "The inverse must have the same size as the matrix",
+            ));
+        }
+
+        if u.len() != n || v.len() != n {
+            return Err(Error::InvalidOperation(
+                "Vector length must be equal to the size of the matrix",
+            ));
+        }
+
+        let inv_u = inv.multiply_vector(u)?;
+        let v_inv = inv.transpose().multiply_vector(v)?;
+
+        let denominator = MatrixElement::one() + v.dot(&inv_u);
+        if denominator.is_zero() {
+            return Err(Error::InvalidOperation("The matrix cannot be inverted"));
+        }
+
+        inv.zip_map(&inv_u.outer(&v_inv), |a, b| a - b / denominator)
+    }
+
+    /// Returns the Frobenius distance between `A * A⁻¹` and the identity matrix, which measures how
+    /// accurately the matrix is inverted.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix cannot be inverted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     4, 7;
+    ///     2, 6;
+    /// ];
+    ///
+    /// assert!(m.inverse_round_trip_error().unwrap().is_zero());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::inverse`]
+    /// * [`Matrix::frobenius_norm`]
+    pub fn inverse_round_trip_error(&self) -> Result<MatrixElement> {
+        let product = self.multiply(&self.inverse()?)?;
+
+        Ok(product
+            .zip_map(&Self::identity(self.rows_number), |a, b| a - b)?
+            .frobenius_norm())
+    }
+
     /// Checks if the matrix can be inverted.
     pub fn can_be_inverted(&self) -> bool {
         self.to_rref()
             .epsilon_equals(&Self::identity(self.rows_number))
     }
+
+    /// Checks if the matrix is singular, which means that its rank is less than its size.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// assert!(matrix![1, 2; 2, 4].is_singular().unwrap());
+    /// assert!(!matrix![1, 2; 3, 4].is_singular().unwrap());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Invertible matrix](https://en.wikipedia.org/wiki/Invertible_matrix)
+    /// * [`Matrix::rank`]
+    /// * [`Matrix::can_be_inverted`]
+    pub fn is_singular(&self) -> Result<bool> {
+        self.assert_square("Only square matrices can be singular")?;
+
+        Ok(self.rank() < self.rows_number)
+    }
 }
 
 #[cfg(test)]
@@ -52,7 +209,6 @@ mod tests {
     use std::ops::Mul;
 
     use super::*;
-    use crate::element::MatrixElement;
     use crate::matrix;
 
     #[test]
@@ -105,5 +261,73 @@ mod tests {
         ];
 
         m.inverse().unwrap_err();
+        m.inverse_round_trip_error().unwrap_err();
+
+        assert!(matches!(
+            m.try_inverse(),
+            Err(Error::InvalidOperation("Cannot divide by zero"))
+        ));
+    }
+
+    #[test]
+    fn inverse_rank1_update() {
+        let m = matrix![
+            4, -2, 1;
+            3, 6, -4;
+            2, 1, 8;
+        ];
+        let inv = m.inverse().unwrap();
+        let u = Vector::new(vec![1.into(), (-2).into(), 0.5.into()]);
+        let v = Vector::new(vec![3.into(), 0.into(), 1.into()]);
+
+        let updated = m.clone() + u.outer(&v);
+
+        assert!(m
+            .inverse_rank1_update(&inv, &u, &v)
+            .unwrap()
+            .epsilon_equals(&updated.inverse().unwrap()));
+
+        // I - e₁e₁ᵀ is singular
+        let e = Vector::new(vec![1.into(), 0.into(), 0.into()]);
+        Matrix::identity(3)
+            .inverse_rank1_update(&Matrix::identity(3), &e, &e.negate())
+            .unwrap_err();
+    }
+
+    #[test]
+    fn inverse_round_trip_error() {
+        assert!(Matrix::identity(4)
+            .inverse_round_trip_error()
+            .unwrap()
+            .is_zero());
+
+        let m = matrix![
+            4, 1, 0;
+            1, 5, 2;
+            0, 2, 6;
+        ];
+
+        assert!(m.inverse_round_trip_error().unwrap().value() < 1e-12);
+    }
+
+    #[test]
+    fn is_singular() {
+        let singular = matrix![
+            1, 2, 3;
+            4, 5, 6;
+            7, 8, 9;
+        ];
+        assert!(singular.is_singular().unwrap());
+        assert!(singular.det().unwrap().is_zero());
+
+        let nonsingular = matrix![
+            2, 0, 1;
+            1, 3, 2;
+            1, 1, 2;
+        ];
+        assert!(!nonsingular.is_singular().unwrap());
+        assert!(nonsingular.can_be_inverted());
+
+        matrix![1, 2, 3].is_singular().unwrap_err();
     }
 }
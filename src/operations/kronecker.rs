@@ -0,0 +1,65 @@
+use crate::Matrix;
+
+impl Matrix {
+    /// Returns the Kronecker product `self ⊗ other`.
+    ///
+    /// The result is the block matrix whose block at `(i, j)` is `self[i, j] * other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let a = matrix![
+    ///     1, 2;
+    ///     3, 4;
+    /// ];
+    ///
+    /// assert!(a.kronecker_product(&Matrix::identity(2)).epsilon_equals(&matrix![
+    ///     1, 0, 2, 0;
+    ///     0, 1, 0, 2;
+    ///     3, 0, 4, 0;
+    ///     0, 3, 0, 4;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Kronecker product](https://en.wikipedia.org/wiki/Kronecker_product)
+    pub fn kronecker_product(&self, other: &Self) -> Self {
+        Matrix::from_fn(
+            self.rows_number * other.rows_number,
+            self.cols_number * other.cols_number,
+            |i, j| {
+                let outer = self.elements
+                    [(i / other.rows_number) * self.cols_number + j / other.cols_number];
+                let inner = other.elements
+                    [(i % other.rows_number) * other.cols_number + j % other.cols_number];
+
+                outer * inner
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, Matrix, MatrixElement};
+
+    #[test]
+    fn kronecker_product() {
+        let a = matrix![1, -1];
+        let b = matrix![
+            2;
+            3;
+        ];
+
+        assert!(a.kronecker_product(&b).epsilon_equals(&matrix![
+            2, -2;
+            3, -3;
+        ]));
+        assert!(b.kronecker_product(&a).epsilon_equals(&matrix![
+            2, -2;
+            3, -3;
+        ]));
+    }
+}
@@ -0,0 +1,250 @@
+use crate::{Error, Matrix, MatrixElement, Result, Vector};
+
+impl Matrix {
+    /// Returns a new matrix with `f` applied to each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, -2, 3;
+    ///     4, 5, -6;
+    /// ];
+    ///
+    /// assert!(m.map(|x| x * x).epsilon_equals(&matrix![
+    ///     1, 4, 9;
+    ///     16, 25, 36;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::scale`]
+    /// * [`Matrix::zip_map`]
+    pub fn map<F: Fn(MatrixElement) -> MatrixElement>(&self, f: F) -> Self {
+        self.as_rows()
+            .into_iter()
+            .map(|row| row.into_iter().map(&f).collect::<Vector>())
+            .collect()
+    }
+
+    /// Returns a new matrix with `f` applied to each pair of corresponding elements of this matrix
+    /// and another matrix.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the dimensions of the two matrices do not match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m1 = matrix![
+    ///     1, 2;
+    ///     3, 4;
+    /// ];
+    /// let m2 = matrix![
+    ///     5, 6;
+    ///     7, 8;
+    /// ];
+    ///
+    /// assert!(m1.zip_map(&m2, |a, b| a * b).unwrap().epsilon_equals(&matrix![
+    ///     5, 12;
+    ///     21, 32;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::map`]
+    /// * [`Matrix::add_s`]
+    pub fn zip_map<F: Fn(MatrixElement, MatrixElement) -> MatrixElement>(
+        &self,
+        other: &Self,
+        f: F,
+    ) -> Result<Self> {
+        if self.rows_number != other.rows_number || self.cols_number != other.cols_number {
+            return Err(Error::InvalidOperation(
+                "Matrix dimensions must match for element-wise operations",
+            ));
+        }
+
+        Ok(self
+            .as_rows()
+            .into_iter()
+            .zip(other.as_rows())
+            .map(|(row1, row2)| {
+                row1.into_iter()
+                    .zip(row2)
+                    .map(|(a, b)| f(a, b))
+                    .collect::<Vector>()
+            })
+            .collect())
+    }
+
+    /// Returns a new matrix with `f` applied to each row.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if `f` returns a vector whose length is different from the number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     3, 4;
+    ///     0, 2;
+    /// ];
+    ///
+    /// let normalized = m.map_rows(|row| row.scale(row.norm().inverse())).unwrap();
+    ///
+    /// assert!(normalized.epsilon_equals(&matrix![
+    ///     0.6, 0.8;
+    ///     0, 1;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::map_cols`]
+    pub fn map_rows<F: Fn(Vector) -> Vector>(&self, f: F) -> Result<Self> {
+        let rows = self.as_rows().into_iter().map(f).collect::<Vec<_>>();
+
+        if rows.iter().any(|row| row.len() != self.cols_number) {
+            return Err(Error::InvalidOperation(
+                "Mapped rows must keep the number of columns",
+            ));
+        }
+
+        Ok(rows.into())
+    }
+
+    /// Returns a new matrix with `f` applied to each column.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if `f` returns a vector whose length is different from the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 2;
+    ///     3, 4;
+    /// ];
+    ///
+    /// assert!(m.map_cols(|col| col.scale(2)).unwrap().epsilon_equals(&matrix![
+    ///     2, 4;
+    ///     6, 8;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::map_rows`]
+    pub fn map_cols<F: Fn(Vector) -> Vector>(&self, f: F) -> Result<Self> {
+        let cols = self.as_cols().into_iter().map(f).collect::<Vec<_>>();
+
+        if cols.iter().any(|col| col.len() != self.rows_number) {
+            return Err(Error::InvalidOperation(
+                "Mapped columns must keep the number of rows",
+            ));
+        }
+
+        Ok(Self::from_cols(cols))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn map() {
+        let m = matrix![
+            1.2, -2.7;
+            3.5, 4;
+            -0.1, 0;
+        ];
+
+        let mapped = m.map(|x| MatrixElement::from(x.trunc()));
+
+        assert_eq!(mapped.rows_number, 3);
+        assert_eq!(mapped.cols_number, 2);
+        assert!(mapped.epsilon_equals(&matrix![
+            1, -2;
+            3, 4;
+            0, 0;
+        ]));
+    }
+
+    #[test]
+    fn zip_map() {
+        let m1 = matrix![
+            1, -5, 3;
+            0.5, 2, -1;
+        ];
+        let m2 = matrix![
+            2, -7, 1;
+            0.25, 4, 0;
+        ];
+
+        let max = m1
+            .zip_map(&m2, |a, b| if a.epsilon_ge(&b) { a } else { b })
+            .unwrap();
+
+        assert!(max.epsilon_equals(&matrix![
+            2, -5, 3;
+            0.5, 4, 0;
+        ]));
+    }
+
+    #[test]
+    fn zip_map_diff_size() {
+        let m1 = matrix![1, 2; 3, 4];
+        let m2 = matrix![1, 2, 3; 4, 5, 6];
+
+        m1.zip_map(&m2, |a, b| a + b).unwrap_err();
+    }
+
+    #[test]
+    fn map_rows() {
+        let m = matrix![
+            1, 2, 2;
+            0, -3, 4;
+        ];
+
+        let normalized = m.map_rows(|row| row.scale(row.norm().inverse())).unwrap();
+
+        for row in normalized.as_rows() {
+            assert!(row.norm().is_one());
+        }
+        assert!(normalized.epsilon_equals(&matrix![
+            1.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0;
+            0, -0.6, 0.8;
+        ]));
+
+        m.map_rows(|row| Vector::new(row.data[1..].to_vec()))
+            .unwrap_err();
+    }
+
+    #[test]
+    fn map_cols() {
+        let m = matrix![
+            1, 2, 2;
+            0, -3, 4;
+        ];
+
+        assert!(m
+            .map_cols(|col| col.negate())
+            .unwrap()
+            .epsilon_equals(&m.scale(-1)));
+
+        m.map_cols(|col| Vector::new(col.data[1..].to_vec()))
+            .unwrap_err();
+    }
+}
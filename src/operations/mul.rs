@@ -1,6 +1,6 @@
 use std::ops::Mul;
 
-use crate::{Error, Matrix, Result};
+use crate::{Error, Matrix, MatrixElement, Result};
 
 impl Matrix {
     /// Returns a new matrix that is the product of this matrix and another matrix.
@@ -39,21 +39,72 @@ impl Matrix {
             ));
         }
 
-        Ok(Matrix::from_cols(
-            other
-                .as_cols()
-                .into_iter()
-                .map(|col| self.multiply_vector(&col).unwrap()) // INFO: safe to unwrap
-                .collect(),
-        ))
+        let m = self.rows_number;
+        let p = self.cols_number;
+        let n = other.cols_number;
+
+        let mut elements = vec![MatrixElement::zero(); m * n];
+
+        // The i-k-j loop order walks both `other` and the result row by row,
+        // which keeps the memory access contiguous.
+        for i in 0..m {
+            let result_row = &mut elements[i * n..(i + 1) * n];
+
+            for k in 0..p {
+                let factor = self.elements[i * p + k];
+                let other_row = &other.elements[k * n..(k + 1) * n];
+
+                for (result, &element) in result_row.iter_mut().zip(other_row) {
+                    *result += factor * element;
+                }
+            }
+        }
+
+        Ok(Matrix {
+            cols_number: n,
+            rows_number: m,
+            elements,
+        })
+    }
+
+    /// Returns a new matrix that is the product of this matrix and another matrix.
+    ///
+    /// This is an alias of [`Matrix::multiply`], named after the `checked_*` methods of the
+    /// standard library. Unlike the `*` operator, it does not panic on a dimension mismatch.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the number of columns of this matrix is different from the number of rows of the other matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m1 = matrix![1, 2; 3, 4];
+    /// let m2 = matrix![1, 2, 3];
+    ///
+    /// assert!(m1.checked_mul(&m2).is_err());
+    /// ```
+    pub fn checked_mul(&self, other: &Self) -> Result<Self> {
+        self.multiply(other)
     }
 }
 
 impl Mul for Matrix {
     type Output = Self;
 
+    /// # Panics
+    ///
+    /// Panics if the number of columns of `self` is different from the number of rows of `rhs`.
+    /// Use [`Matrix::checked_mul`] to get an error instead.
     fn mul(self, rhs: Self) -> Self::Output {
-        self.multiply(&rhs).unwrap()
+        match self.multiply(&rhs) {
+            Ok(result) => result,
+            Err(err) => panic!(
+                "Cannot multiply a {}x{} matrix by a {}x{} matrix: {}",
+                self.rows_number, self.cols_number, rhs.rows_number, rhs.cols_number, err
+            ),
+        }
     }
 }
 
@@ -99,5 +150,12 @@ mod tests {
         let m2 = matrix![1, 2; 3, 4; 5, 6;];
 
         m1.multiply(&m2).unwrap_err();
+        m1.checked_mul(&m2).unwrap_err();
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot multiply a 2x2 matrix by a 3x2 matrix")]
+    fn mul_wrong_size_panic_message() {
+        let _ = matrix![1, 2; 3, 4;] * matrix![1, 2; 3, 4; 5, 6;];
     }
 }
@@ -0,0 +1,93 @@
+use rayon::prelude::*;
+
+use crate::{Error, Matrix, Result};
+
+impl Matrix {
+    /// Returns a new matrix that is the product of this matrix and another matrix, computing the
+    /// columns of the result in parallel.
+    ///
+    /// The result is exactly the same as [`Matrix::multiply`].
+    ///
+    /// NOTE: This method is only available with the `rayon` feature.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the number of columns of this matrix is different from the number of rows of the other matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m1 = matrix![
+    ///     -1, 2;
+    ///     1 / 3, 4;
+    /// ];
+    ///
+    /// let m2 = matrix![
+    ///     1, 0.2;
+    ///     4, -5;
+    /// ];
+    ///
+    /// assert_eq!(m1.multiply_parallel(&m2).unwrap(), m1.multiply(&m2).unwrap());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::multiply`]
+    /// * [`Matrix::multiply_vector`]
+    pub fn multiply_parallel(&self, other: &Self) -> Result<Self> {
+        if self.cols_number != other.rows_number {
+            return Err(Error::InvalidOperation(
+                "Matrix multiplication is only available for MxP * PxN",
+            ));
+        }
+
+        // `from_cols` needs at least one column
+        if other.cols_number == 0 {
+            return Ok(Matrix::zero(self.rows_number, 0));
+        }
+
+        Ok(Matrix::from_cols(
+            other
+                .as_cols()
+                .into_par_iter()
+                .map(|col| self.multiply_vector(&col).unwrap()) // INFO: safe to unwrap
+                .collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, MatrixElement};
+
+    #[test]
+    fn multiply_parallel() {
+        let m1 = Matrix::from_fn(37, 23, |i, j| {
+            MatrixElement::from(((i * 7 + j * 3) % 11) as f64 / 3.0 - 1.5)
+        });
+        let m2 = Matrix::from_fn(23, 41, |i, j| {
+            MatrixElement::from(((i * 5 + j * 13) % 17) as f64 / 7.0)
+        });
+
+        assert_eq!(
+            m1.multiply_parallel(&m2).unwrap(),
+            m1.multiply(&m2).unwrap()
+        );
+
+        let empty = Matrix::zero(23, 0);
+        assert_eq!(
+            m1.multiply_parallel(&empty).unwrap(),
+            m1.multiply(&empty).unwrap()
+        );
+    }
+
+    #[test]
+    fn multiply_parallel_wrong_size() {
+        let m1 = matrix![1, 2; 3, 4;];
+        let m2 = matrix![1, 2; 3, 4; 5, 6;];
+
+        m1.multiply_parallel(&m2).unwrap_err();
+    }
+}
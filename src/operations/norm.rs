@@ -0,0 +1,176 @@
+use crate::{Error, Matrix, MatrixElement, Result};
+
+impl Matrix {
+    /// Returns the Frobenius norm of the matrix, which is the square root of the sum of the squares
+    /// of all elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, -2;
+    ///     2, 4;
+    /// ];
+    ///
+    /// assert!(m.frobenius_norm().epsilon_equals(&5));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Frobenius norm](https://en.wikipedia.org/wiki/Matrix_norm#Frobenius_norm)
+    /// * [`Vector::norm`](crate::Vector::norm)
+    pub fn frobenius_norm(&self) -> MatrixElement {
+        self.as_rows()
+            .into_iter()
+            .map(|row| row.dot(&row))
+            .fold(MatrixElement::zero(), |acc, x| acc + x)
+            .sqrt()
+    }
+
+    /// Returns the weighted Frobenius norm of the matrix, which is
+    /// `sqrt(Σ weights[i, j] * self[i, j]²)`.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the dimensions of the two matrices do not match, or if any weight is
+    /// negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 2;
+    ///     3, 4;
+    /// ];
+    /// let weights = matrix![
+    ///     9, 0;
+    ///     0, 1;
+    /// ];
+    ///
+    /// assert!(m.weighted_frobenius_norm(&weights).unwrap().epsilon_equals(&5));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::frobenius_norm`]
+    pub fn weighted_frobenius_norm(&self, weights: &Self) -> Result<MatrixElement> {
+        if self.rows_number != weights.rows_number || self.cols_number != weights.cols_number {
+            return Err(Error::InvalidOperation(
+                "Weights dimensions must match the matrix dimensions",
+            ));
+        }
+
+        if weights
+            .as_rows()
+            .into_iter()
+            .flatten()
+            .any(|weight| weight.epsilon_lt(&MatrixElement::zero()))
+        {
+            return Err(Error::InvalidOperation("Weights must not be negative"));
+        }
+
+        Ok(self
+            .zip_map(weights, |element, weight| weight * element * element)?
+            .as_rows()
+            .into_iter()
+            .flatten()
+            .fold(MatrixElement::zero(), |acc, x| acc + x)
+            .sqrt())
+    }
+
+    /// Returns the Frobenius inner product of two matrices, which is `Σ self[i, j] * other[i, j]`.
+    ///
+    /// It equals `trace(selfᵀ * other)`, without forming the product.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the dimensions of the two matrices do not match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let a = matrix![
+    ///     1, 2;
+    ///     3, 4;
+    /// ];
+    /// let b = matrix![
+    ///     0, 1;
+    ///     -1, 2;
+    /// ];
+    ///
+    /// assert!(a.frobenius_inner(&b).unwrap().epsilon_equals(&7));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Frobenius inner product](https://en.wikipedia.org/wiki/Frobenius_inner_product)
+    /// * [`Matrix::frobenius_norm`]
+    pub fn frobenius_inner(&self, other: &Self) -> Result<MatrixElement> {
+        if self.rows_number != other.rows_number || self.cols_number != other.cols_number {
+            return Err(Error::InvalidOperation(
+                "Matrix dimensions must match to compute the inner product",
+            ));
+        }
+
+        Ok(self
+            .elements
+            .iter()
+            .zip(&other.elements)
+            .fold(MatrixElement::zero(), |acc, (&a, &b)| acc + a * b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn frobenius_norm() {
+        assert!(Matrix::identity(4).frobenius_norm().epsilon_equals(&2));
+        assert!(Matrix::zero(2, 3).frobenius_norm().is_zero());
+    }
+
+    #[test]
+    fn weighted_frobenius_norm() {
+        let m = matrix![
+            1, -2, 3.5;
+            0.5, 4, -6;
+        ];
+
+        assert!(m
+            .weighted_frobenius_norm(&Matrix::from_fn(2, 3, |_, _| MatrixElement::one()))
+            .unwrap()
+            .epsilon_equals(&m.frobenius_norm()));
+
+        m.weighted_frobenius_norm(&Matrix::zero(3, 2)).unwrap_err();
+        m.weighted_frobenius_norm(&matrix![1, 1, 1; 1, -1, 1])
+            .unwrap_err();
+    }
+
+    #[test]
+    fn frobenius_inner() {
+        let a = matrix![
+            1, -2, 3.5;
+            0.5, 4, -6;
+        ];
+        let b = matrix![
+            2, 0, -1;
+            3, 1.5, 2;
+        ];
+
+        assert!(a
+            .frobenius_inner(&b)
+            .unwrap()
+            .epsilon_equals(&(a.transpose() * b.clone()).trace().unwrap()));
+        assert!(a
+            .frobenius_inner(&a)
+            .unwrap()
+            .epsilon_equals(&a.frobenius_norm().powi(2)));
+
+        a.frobenius_inner(&b.transpose()).unwrap_err();
+    }
+}
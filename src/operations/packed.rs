@@ -0,0 +1,110 @@
+use crate::{Error, Matrix, MatrixElement, Result};
+
+impl Matrix {
+    /// Creates a symmetric matrix of size `n × n` from its upper triangle, stored row by row.
+    ///
+    /// `data` must hold the `n * (n + 1) / 2` elements on and above the main diagonal, which are
+    /// mirrored below it.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if `n` is zero or if the length of `data` is not `n * (n + 1) / 2`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let data = [1, 2, 3, 4, 5, 6].map(MatrixElement::from);
+    ///
+    /// assert!(Matrix::from_upper_triangle(&data, 3).unwrap().epsilon_equals(&matrix![
+    ///     1, 2, 3;
+    ///     2, 4, 5;
+    ///     3, 5, 6;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::to_upper_triangle`]
+    pub fn from_upper_triangle(data: &[MatrixElement], n: usize) -> Result<Self> {
+        if n == 0 {
+            return Err(Error::InvalidOperation("Matrix must have at least one row"));
+        }
+
+        if data.len() != n * (n + 1) / 2 {
+            return Err(Error::InvalidOperation(
+                "Packed data length must be n * (n + 1) / 2",
+            ));
+        }
+
+        // the upper triangle of row `i` starts after the `i` previous rows of decreasing length
+        let offset = |i: usize| i * n - i * (i.saturating_sub(1)) / 2;
+
+        Ok(Matrix::from_fn(n, n, |i, j| {
+            let (i, j) = if i <= j { (i, j) } else { (j, i) };
+            data[offset(i) + j - i]
+        }))
+    }
+
+    /// Returns the elements on and above the main diagonal, row by row.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 2;
+    ///     2, 3;
+    /// ];
+    ///
+    /// assert_eq!(m.to_upper_triangle().unwrap(), [1, 2, 3].map(MatrixElement::from));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::from_upper_triangle`]
+    pub fn to_upper_triangle(&self) -> Result<Vec<MatrixElement>> {
+        self.assert_square("Only square matrices can be packed")?;
+
+        let n = self.rows_number;
+
+        Ok((0..n)
+            .flat_map(|i| self.elements[i * n + i..(i + 1) * n].iter().copied())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, Matrix, MatrixElement};
+
+    #[test]
+    fn upper_triangle_round_trip() {
+        let m = matrix![
+            4, 1, -2, 0.5;
+            1, 3, 0, 7;
+            -2, 0, 5, -1;
+            0.5, 7, -1, 2;
+        ];
+
+        let packed = m.to_upper_triangle().unwrap();
+
+        assert_eq!(packed.len(), 10);
+        assert!(Matrix::from_upper_triangle(&packed, 4)
+            .unwrap()
+            .epsilon_equals(&m));
+    }
+
+    #[test]
+    fn upper_triangle_invalid() {
+        let data = [1, 2, 3, 4].map(MatrixElement::from);
+
+        Matrix::from_upper_triangle(&data, 2).unwrap_err();
+        Matrix::from_upper_triangle(&[], 0).unwrap_err();
+        matrix![1, 2].to_upper_triangle().unwrap_err();
+    }
+}
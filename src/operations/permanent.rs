@@ -0,0 +1,97 @@
+use crate::{Error, Matrix, MatrixElement, Result};
+
+/// The largest size for which [`Matrix::permanent`] enumerates the column subsets.
+const MAX_SIZE: usize = 20;
+
+impl Matrix {
+    /// Returns the permanent of the matrix, which is defined like the determinant but without the
+    /// alternating signs.
+    ///
+    /// It is computed with Ryser's formula in `O(2ⁿ * n²)` time, so it is only practical for
+    /// small matrices.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square, or if it is larger than `20 × 20`, since the
+    /// computation time grows exponentially with the size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 2;
+    ///     3, 4;
+    /// ];
+    ///
+    /// assert!(m.permanent().unwrap().epsilon_equals(&10));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Permanent](https://en.wikipedia.org/wiki/Permanent_(mathematics))
+    /// * Wikipedia: [Ryser formula](https://en.wikipedia.org/wiki/Computing_the_permanent#Ryser_formula)
+    /// * [`Matrix::det`]
+    pub fn permanent(&self) -> Result<MatrixElement> {
+        self.assert_square("Only square matrices have permanents")?;
+
+        let n = self.rows_number;
+        if n > MAX_SIZE {
+            return Err(Error::InvalidOperation(
+                "Permanents are only computed for matrices up to 20 × 20",
+            ));
+        }
+
+        // the empty product over no rows
+        if n == 0 {
+            return Ok(MatrixElement::one());
+        }
+
+        let rows = self.as_rows();
+
+        // perm(A) = (-1)ⁿ Σ_{S ⊆ cols} (-1)^|S| Π_i Σ_{j ∈ S} A[i, j]
+        let mut sum = MatrixElement::zero();
+        for subset in 1usize..(1 << n) {
+            let product = rows.iter().fold(MatrixElement::one(), |acc, row| {
+                let row_sum = (0..n)
+                    .filter(|j| subset & (1 << j) != 0)
+                    .fold(MatrixElement::zero(), |acc, j| acc + row[j]);
+
+                acc * row_sum
+            });
+
+            if (n - subset.count_ones() as usize).is_multiple_of(2) {
+                sum += product;
+            } else {
+                sum -= product;
+            }
+        }
+
+        Ok(sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, Matrix, MatrixElement};
+
+    #[test]
+    fn permanent() {
+        // 1 * (5 * 9 + 6 * 8) + 2 * (4 * 9 + 6 * 7) + 3 * (4 * 8 + 5 * 7) = 450
+        let m = matrix![
+            1, 2, 3;
+            4, 5, 6;
+            7, 8, 9;
+        ];
+        assert!(m.permanent().unwrap().epsilon_equals(&450));
+
+        // the permanent of the all-ones matrix counts the permutations
+        let ones = matrix![1; 5, 5];
+        assert!(ones.permanent().unwrap().epsilon_equals(&120));
+
+        assert!(Matrix::identity(4).permanent().unwrap().is_one());
+
+        matrix![1, 2, 3].permanent().unwrap_err();
+        Matrix::identity(64).permanent().unwrap_err();
+    }
+}
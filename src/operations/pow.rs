@@ -0,0 +1,147 @@
+use crate::{Error, Matrix, Result};
+
+impl Matrix {
+    /// Raises the matrix to a non-negative integer power by repeated squaring.
+    ///
+    /// `A⁰` is the identity matrix.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square, or if an intermediate result overflows to
+    /// infinite or `NaN` values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let fibonacci = matrix![
+    ///     1, 1;
+    ///     1, 0;
+    /// ];
+    ///
+    /// assert!(fibonacci.pow(10).unwrap().epsilon_equals(&matrix![
+    ///     89, 55;
+    ///     55, 34;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Exponentiation by squaring](https://en.wikipedia.org/wiki/Exponentiation_by_squaring)
+    pub fn pow(&self, n: u32) -> Result<Self> {
+        self.assert_square("Only square matrices can be raised to a power")?;
+
+        let mut result = Matrix::identity(self.rows_number);
+        let mut base = self.clone();
+        let mut n = n;
+
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result.multiply(&base)?._assert_finite()?;
+            }
+
+            n >>= 1;
+            if n > 0 {
+                base = base.multiply(&base)?._assert_finite()?;
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Raises the matrix to an integer power, which may be negative.
+    ///
+    /// For a negative exponent `-n`, the result is the `n`-th power of the inverse.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square, if the exponent is negative and the matrix
+    /// cannot be inverted, or if an intermediate result overflows to infinite or `NaN` values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     2, 0;
+    ///     0, 4;
+    /// ];
+    ///
+    /// assert!(m.powi(-2).unwrap().epsilon_equals(&matrix![
+    ///     0.25, 0;
+    ///     0, 0.0625;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::pow`]
+    /// * [`Matrix::inverse`]
+    pub fn powi(&self, exp: i32) -> Result<Self> {
+        if exp < 0 {
+            self.assert_square("Only square matrices can be raised to a power")?;
+
+            self.inverse()?.pow(exp.unsigned_abs())
+        } else {
+            self.pow(exp.unsigned_abs())
+        }
+    }
+
+    fn _assert_finite(self) -> Result<Self> {
+        if self.elements.iter().all(|x| x.value().is_finite()) {
+            Ok(self)
+        } else {
+            Err(Error::InvalidOperation(
+                "Matrix power overflowed to non-finite values",
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, Matrix, MatrixElement};
+
+    #[test]
+    fn pow() {
+        let m = matrix![
+            2, 1;
+            0, 3;
+        ];
+
+        assert!(m.pow(0).unwrap().epsilon_equals(&Matrix::identity(2)));
+        assert!(m.pow(1).unwrap().epsilon_equals(&m));
+        assert!(m
+            .pow(5)
+            .unwrap()
+            .epsilon_equals(&(m.clone() * m.clone() * m.clone() * m.clone() * m.clone())));
+
+        matrix![1, 2, 3].pow(2).unwrap_err();
+    }
+
+    #[test]
+    fn pow_overflow() {
+        let m = matrix![
+            2, 1;
+            1, 2;
+        ];
+
+        // the largest eigenvalue is 3, and 3^1000 is far beyond f64::MAX
+        m.pow(1000).unwrap_err();
+    }
+
+    #[test]
+    fn powi() {
+        let m = matrix![
+            2, 1;
+            1, 1;
+        ];
+
+        assert!(m.powi(-1).unwrap().epsilon_equals(&m.inverse().unwrap()));
+        assert!(m.powi(3).unwrap().epsilon_equals(&m.pow(3).unwrap()));
+        assert!((m.powi(-3).unwrap() * m.powi(3).unwrap()).epsilon_equals(&Matrix::identity(2)));
+
+        matrix![1, 2; 2, 4].powi(-1).unwrap_err();
+        matrix![1, 2, 3].powi(-1).unwrap_err();
+    }
+}
@@ -0,0 +1,292 @@
+use crate::Matrix;
+
+impl Matrix {
+    /// Checks if the matrix is symmetric, which means that it is equal to its transpose.
+    ///
+    /// Non-square matrices are never symmetric.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// assert!(matrix![1, 2; 2, 1].is_symmetric());
+    /// assert!(!matrix![1, 2; 3, 1].is_symmetric());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Symmetric matrix](https://en.wikipedia.org/wiki/Symmetric_matrix)
+    pub fn is_symmetric(&self) -> bool {
+        self.rows_number == self.cols_number && self.epsilon_equals(&self.transpose())
+    }
+
+    /// Checks if the matrix is orthogonal, which means that its transpose is its inverse.
+    ///
+    /// Non-square matrices are never orthogonal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// assert!(matrix![0, 1; 1, 0].is_orthogonal());
+    /// assert!(!matrix![1, 1; 0, 1].is_orthogonal());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Orthogonal matrix](https://en.wikipedia.org/wiki/Orthogonal_matrix)
+    pub fn is_orthogonal(&self) -> bool {
+        if self.rows_number != self.cols_number {
+            return false;
+        }
+
+        (self.transpose() * self.clone()).epsilon_equals(&Matrix::identity(self.rows_number))
+    }
+
+    /// Checks if the matrix is in upper Hessenberg form, which means that all elements below the
+    /// first subdiagonal are zero.
+    ///
+    /// Non-square matrices are never upper Hessenberg.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// assert!(matrix![
+    ///     1, 2, 3;
+    ///     4, 5, 6;
+    ///     0, 7, 8;
+    /// ].is_upper_hessenberg());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Hessenberg matrix](https://en.wikipedia.org/wiki/Hessenberg_matrix)
+    pub fn is_upper_hessenberg(&self) -> bool {
+        if self.rows_number != self.cols_number {
+            return false;
+        }
+
+        (0..self.rows_number).all(|i| {
+            (0..i.saturating_sub(1)).all(|j| self.elements[i * self.cols_number + j].is_zero())
+        })
+    }
+
+    /// Checks if the matrix is a Toeplitz matrix, which means that each diagonal is constant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// assert!(matrix![
+    ///     1, 2, 3;
+    ///     4, 1, 2;
+    /// ].is_toeplitz());
+    /// assert!(!matrix![1, 2; 3, 4].is_toeplitz());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Toeplitz matrix](https://en.wikipedia.org/wiki/Toeplitz_matrix)
+    /// * [`Matrix::is_circulant`]
+    pub fn is_toeplitz(&self) -> bool {
+        let n = self.cols_number;
+
+        (1..self.rows_number).all(|i| {
+            (1..n).all(|j| {
+                self.elements[i * n + j].epsilon_equals(&self.elements[(i - 1) * n + j - 1])
+            })
+        })
+    }
+
+    /// Checks if the matrix is circulant, which means that it is square and each row is the row
+    /// above it cyclically shifted one position to the right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// assert!(matrix![
+    ///     1, 2, 3;
+    ///     3, 1, 2;
+    ///     2, 3, 1;
+    /// ].is_circulant());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Circulant matrix](https://en.wikipedia.org/wiki/Circulant_matrix)
+    /// * [`Matrix::is_toeplitz`]
+    pub fn is_circulant(&self) -> bool {
+        if self.rows_number != self.cols_number {
+            return false;
+        }
+
+        let n = self.cols_number;
+
+        (1..n).all(|i| {
+            (0..n).all(|j| {
+                self.elements[i * n + j]
+                    .epsilon_equals(&self.elements[(i - 1) * n + (j + n - 1) % n])
+            })
+        })
+    }
+
+    /// Checks if the matrix is normal, which means that it commutes with its transpose.
+    ///
+    /// Symmetric and orthogonal matrices are normal. Non-square matrices are never normal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// assert!(matrix![1, 2; 2, 1].is_normal());
+    /// assert!(!matrix![1, 2; 0, 1].is_normal());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Normal matrix](https://en.wikipedia.org/wiki/Normal_matrix)
+    pub fn is_normal(&self) -> bool {
+        if self.rows_number != self.cols_number {
+            return false;
+        }
+
+        let transpose = self.transpose();
+
+        (transpose.clone() * self.clone()).epsilon_equals(&(self.clone() * transpose))
+    }
+
+    /// Checks if the matrix is symmetric positive-definite, which means that all its eigenvalues
+    /// are positive.
+    ///
+    /// Non-symmetric matrices are never positive-definite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// assert!(matrix![2, -1; -1, 2].is_positive_definite());
+    /// assert!(!matrix![1, 2; 2, 1].is_positive_definite());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Definite matrix](https://en.wikipedia.org/wiki/Definite_matrix)
+    /// * [`Matrix::eigenvalues_symmetric`]
+    pub fn is_positive_definite(&self) -> bool {
+        if !self.is_symmetric() {
+            return false;
+        }
+
+        // INFO: safe to unwrap, since the matrix is symmetric
+        self.eigenvalues_symmetric()
+            .unwrap()
+            .into_iter()
+            .all(|value| value.is_positive() && !value.is_zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, MatrixElement};
+
+    #[test]
+    fn is_normal() {
+        let symmetric = matrix![
+            4, 1, -2;
+            1, 3, 0;
+            -2, 0, 5;
+        ];
+        assert!(symmetric.is_normal());
+
+        let (s, c) = 1.2_f64.sin_cos();
+        let orthogonal = matrix![
+            c, -s;
+            s, c;
+        ];
+        assert!(orthogonal.is_normal());
+
+        let general = matrix![
+            1, 2, 3;
+            4, 5, 6;
+            7, 8, 10;
+        ];
+        assert!(!general.is_normal());
+
+        assert!(!matrix![1, 2, 3; 4, 5, 6].is_normal());
+    }
+
+    #[test]
+    fn is_upper_hessenberg() {
+        let hessenberg = matrix![
+            1, 2, 3, 4;
+            5, 6, 7, 8;
+            0, 9, 10, 11;
+            0, 0, 12, 13;
+        ];
+        assert!(hessenberg.is_upper_hessenberg());
+
+        let full = matrix![
+            1, 2, 3, 4;
+            5, 6, 7, 8;
+            9, 10, 11, 12;
+            13, 14, 15, 16;
+        ];
+        assert!(!full.is_upper_hessenberg());
+
+        assert!(matrix![1].is_upper_hessenberg());
+        assert!(!matrix![1, 2, 3; 4, 5, 6].is_upper_hessenberg());
+    }
+
+    #[test]
+    fn is_toeplitz_and_circulant() {
+        let toeplitz = matrix![
+            1, 2, 3, 4;
+            5, 1, 2, 3;
+            6, 5, 1, 2;
+        ];
+        assert!(toeplitz.is_toeplitz());
+        assert!(!toeplitz.is_circulant());
+
+        let circulant = matrix![
+            1, 2, 3, 4;
+            4, 1, 2, 3;
+            3, 4, 1, 2;
+            2, 3, 4, 1;
+        ];
+        assert!(circulant.is_toeplitz());
+        assert!(circulant.is_circulant());
+
+        let general = matrix![
+            1, 2, 3;
+            4, 5, 6;
+            7, 8, 9;
+        ];
+        assert!(!general.is_toeplitz());
+        assert!(!general.is_circulant());
+    }
+
+    #[test]
+    fn is_symmetric_and_orthogonal() {
+        let (s, c) = 0.4_f64.sin_cos();
+        let rotation = matrix![
+            c, -s, 0;
+            s, c, 0;
+            0, 0, 1;
+        ];
+        assert!(rotation.is_orthogonal());
+        assert!(!rotation.is_symmetric());
+
+        let symmetric = matrix![
+            2, 1;
+            1, 2;
+        ];
+        assert!(symmetric.is_symmetric());
+        assert!(!symmetric.is_orthogonal());
+
+        assert!(!matrix![1, 0, 0; 0, 1, 0].is_orthogonal());
+        assert!(!matrix![1, 0, 0; 0, 1, 0].is_symmetric());
+    }
+}
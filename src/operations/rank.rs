@@ -0,0 +1,292 @@
+use crate::{Error, Matrix, MatrixElement, Result};
+
+impl Matrix {
+    /// Returns the rank of the matrix, which is the number of non-zero rows of its reduced row
+    /// echelon form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 2, 3;
+    ///     4, 5, 6;
+    ///     7, 8, 9;
+    /// ];
+    ///
+    /// assert_eq!(m.rank(), 2);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Rank](https://en.wikipedia.org/wiki/Rank_(linear_algebra))
+    /// * [`Matrix::to_rref`]
+    /// * [`Matrix::rank_qr`]
+    pub fn rank(&self) -> usize {
+        self.rref_and_rank().1
+    }
+
+    /// Returns the nullity of the matrix, which is the dimension of its null space.
+    ///
+    /// By the rank–nullity theorem, it is the number of columns minus the rank.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 2, 3;
+    ///     2, 4, 6;
+    /// ];
+    ///
+    /// assert_eq!(m.nullity(), 2);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Rank–nullity theorem](https://en.wikipedia.org/wiki/Rank%E2%80%93nullity_theorem)
+    /// * [`Matrix::rank`]
+    /// * [`Matrix::null_space`]
+    pub fn nullity(&self) -> usize {
+        self.cols_number - self.rank()
+    }
+
+    /// Returns the reduced row echelon form of the matrix together with the rank, computed from the
+    /// same reduction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 2, 3;
+    ///     2, 4, 6;
+    /// ];
+    ///
+    /// let (rref, rank) = m.rref_and_rank();
+    ///
+    /// assert_eq!(rank, 1);
+    /// assert!(rref.epsilon_equals(&matrix![
+    ///     1, 2, 3;
+    ///     0, 0, 0;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::to_rref`]
+    /// * [`Matrix::rank`]
+    pub fn rref_and_rank(&self) -> (Self, usize) {
+        let rref = self.to_rref();
+        let rank = rref
+            .as_rows()
+            .into_iter()
+            .filter(|row| !row.is_zero())
+            .count();
+
+        (rref, rank)
+    }
+
+    /// Returns the numerical rank of the matrix, which is the number of diagonal entries of `R`
+    /// above `tol` in the QR decomposition with column pivoting.
+    ///
+    /// It is more reliable than [`Matrix::rank`] for floating-point data, since nearly dependent
+    /// columns are not counted.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if `tol` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 1.000001;
+    ///     1, 1;
+    /// ];
+    ///
+    /// assert_eq!(m.rank(), 2);
+    /// assert_eq!(m.rank_qr(1e-4).unwrap(), 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [QR decomposition with column pivoting](https://en.wikipedia.org/wiki/QR_decomposition#Column_pivoting)
+    /// * [`Matrix::rank`]
+    pub fn rank_qr(&self, tol: f64) -> Result<usize> {
+        if tol < 0.0 {
+            return Err(Error::InvalidOperation("Tolerance must not be negative"));
+        }
+
+        let tol = MatrixElement::from(tol);
+        let mut cols = self.as_cols();
+        let mut rank = 0;
+
+        // modified Gram-Schmidt, always taking the remaining column with the largest norm
+        while rank < cols.len() {
+            let (index, norm) = cols
+                .iter()
+                .enumerate()
+                .skip(rank)
+                .map(|(i, col)| (i, col.norm()))
+                .fold((rank, MatrixElement::zero()), |max, (i, norm)| {
+                    if norm > max.1 {
+                        (i, norm)
+                    } else {
+                        max
+                    }
+                });
+
+            // `norm` is the next diagonal entry of `R`
+            if norm <= tol {
+                break;
+            }
+
+            cols.swap(rank, index);
+
+            let q = cols[rank].scale(norm.inverse());
+            for col in cols.iter_mut().skip(rank + 1) {
+                *col = col.subtract(&q.scale(q.dot(col)));
+            }
+
+            rank += 1;
+        }
+
+        Ok(rank)
+    }
+
+    /// Checks if the matrix is nearly rank deficient, which means that its smallest singular value
+    /// is below `tol`.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if `tol` is negative, or if the matrix has no columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 1;
+    ///     1, 1.0000001;
+    /// ];
+    ///
+    /// assert!(m.is_approx_rank_deficient(1e-6).unwrap());
+    /// assert!(!Matrix::identity(2).is_approx_rank_deficient(1e-6).unwrap());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::singular_values`]
+    /// * [`Matrix::rank_qr`]
+    pub fn is_approx_rank_deficient(&self, tol: f64) -> Result<bool> {
+        if tol < 0.0 {
+            return Err(Error::InvalidOperation("Tolerance must not be negative"));
+        }
+
+        let values = self.singular_values();
+
+        match values.data.last() {
+            Some(&smallest) => Ok(smallest < MatrixElement::from(tol)),
+            None => Err(Error::InvalidOperation(
+                "Empty matrices have no singular values",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn rank() {
+        assert_eq!(Matrix::identity(4).rank(), 4);
+        assert_eq!(Matrix::zero(2, 3).rank(), 0);
+        assert_eq!(
+            matrix![
+                0, -3, -6, 4, 9;
+                -1, -2, -1, 3, 1;
+                -2, -3, 0, 3, -1;
+                1, 4, 5, -9, -7;
+            ]
+            .rank(),
+            3
+        );
+    }
+
+    #[test]
+    fn rref_and_rank() {
+        let m = matrix![
+            0, 3, -6, 6, 4, -5;
+            3, -7, 8, -5, 8, 9;
+            3, -9, 12, -9, 6, 15;
+        ];
+
+        let (rref, rank) = m.rref_and_rank();
+
+        assert_eq!(rank, m.rank());
+        assert!(rref.epsilon_equals(&m.to_rref()));
+    }
+
+    #[test]
+    fn nullity() {
+        let m = matrix![
+            1, 2, 3, 4;
+            2, 4, 7, 9;
+            3, 6, 10, 13;
+        ];
+
+        assert_eq!(m.nullity(), 2);
+        assert_eq!(m.nullity() + m.rank(), m.cols_number);
+        assert_eq!(m.nullity(), m.null_space().len());
+
+        assert_eq!(Matrix::identity(3).nullity(), 0);
+    }
+
+    #[test]
+    fn rank_qr() {
+        let m = matrix![
+            1, 2, 3;
+            4, 5, 6;
+            7, 8, 9;
+            10, 11, 12;
+        ];
+        assert_eq!(m.rank_qr(1e-8).unwrap(), m.rank());
+
+        // the third column is the sum of the first two up to a tiny perturbation
+        let m = matrix![
+            1, 0, 1.000001;
+            0, 1, 1;
+            1, 1, 2;
+        ];
+        assert_eq!(m.rank(), 3);
+        assert_eq!(m.rank_qr(1e-4).unwrap(), 2);
+
+        m.rank_qr(-1.0).unwrap_err();
+    }
+
+    #[test]
+    fn is_approx_rank_deficient() {
+        let well_conditioned = matrix![
+            4, 1, 0;
+            1, 3, 1;
+            0, 1, 2;
+        ];
+        assert!(!well_conditioned.is_approx_rank_deficient(1e-6).unwrap());
+
+        let nearly_singular = matrix![
+            1, 2, 3;
+            4, 5, 6;
+            7, 8, 9.00000001;
+        ];
+        assert!(nearly_singular.is_approx_rank_deficient(1e-6).unwrap());
+
+        well_conditioned.is_approx_rank_deficient(-1.0).unwrap_err();
+        Matrix::zero(1, 0)
+            .is_approx_rank_deficient(1e-6)
+            .unwrap_err();
+    }
+}
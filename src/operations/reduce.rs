@@ -28,7 +28,7 @@ impl Matrix {
     /// * Wikipedia: [Row echelon form](https://en.wikipedia.org/wiki/Row_echelon_form#(General)_row_echelon_form)
     /// * [`Matrix::to_rref`]
     pub fn row_echelon(&self) -> Result<(Self, usize)> {
-        let (output, _, swap_count) = self._row_echelon(None)?;
+        let (output, _, swap_count) = self._row_echelon(None, 1.0)?;
 
         Ok((output, swap_count))
     }
@@ -57,7 +57,7 @@ impl Matrix {
     /// * Wikipedia: [Reduced row echelon form](https://en.wikipedia.org/wiki/Row_echelon_form#Reduced_row_echelon_form)
     /// * [`Matrix::to_rref_apply_to`]
     pub fn to_rref(&self) -> Self {
-        self._to_rref(None).unwrap().0
+        self._to_rref(None, 1.0).unwrap().0
     }
 
     /// Returns the reduced row echelon form of the matrix and applies the same steps to another matrix.
@@ -87,10 +87,84 @@ impl Matrix {
     /// * [`Matrix::to_rref`]
     /// * [`Matrix::inverse`]
     pub fn to_rref_apply_to(&self, other: Self) -> Result<(Self, Self)> {
-        self._to_rref(Some(other))
+        self._to_rref(Some(other), 1.0)
     }
 
-    fn _row_echelon(&self, apply_to: Option<Self>) -> Result<(Self, Self, usize)> {
+    /// Returns the reduced row echelon form of the matrix, computed with threshold pivoting.
+    ///
+    /// A row is swapped into the pivot position only if the current pivot is smaller than
+    /// `threshold` times the largest candidate in its column. With `threshold = 1.0` this is the
+    /// partial pivoting of [`Matrix::to_rref`]. Smaller thresholds swap less often, which preserves
+    /// the structure of the matrix at the cost of numerical stability; with `threshold = 0.0` rows
+    /// are only swapped to avoid zero pivots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 2;
+    ///     4, 3;
+    /// ];
+    ///
+    /// assert!(m.to_rref_threshold(0.1).epsilon_equals(&Matrix::identity(2)));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Pivot element](https://en.wikipedia.org/wiki/Pivot_element)
+    /// * [`Matrix::to_rref`]
+    pub fn to_rref_threshold(&self, threshold: f64) -> Self {
+        self._to_rref(None, threshold).unwrap().0
+    }
+
+    /// Returns the index of the row whose element in column `col` has the largest absolute value,
+    /// searching only the rows at or below `from_row`.
+    ///
+    /// If several elements are equally large (within epsilon), the first one is returned.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the row or column index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     9, 1;
+    ///     2, -7;
+    ///     -5, 3;
+    /// ];
+    ///
+    /// assert_eq!(m.argmax_abs_in_column(0, 0).unwrap(), 0);
+    /// assert_eq!(m.argmax_abs_in_column(0, 1).unwrap(), 2);
+    /// assert_eq!(m.argmax_abs_in_column(1, 0).unwrap(), 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Partial pivoting](https://en.wikipedia.org/wiki/Pivot_element#Partial,_rook,_and_complete_pivoting)
+    pub fn argmax_abs_in_column(&self, col: usize, from_row: usize) -> Result<usize> {
+        self.assert_index(from_row, col)?;
+
+        let mut max_index = from_row;
+        for k in from_row..self.rows_number {
+            if self
+                .get(k, col)?
+                .abs()
+                .epsilon_gt(&self.get(max_index, col)?.abs())
+            {
+                max_index = k;
+            }
+        }
+
+        Ok(max_index)
+    }
+
+    /// A row is swapped into the pivot position only if the current pivot is smaller than
+    /// `threshold` times the largest candidate, so `1.0` is partial pivoting.
+    fn _row_echelon(&self, apply_to: Option<Self>, threshold: f64) -> Result<(Self, Self, usize)> {
         let m = self.rows_number;
         let n = self.cols_number;
 
@@ -113,20 +187,14 @@ impl Matrix {
 
         while i < m && j < n {
             // find the element with the largest absolute value in the current column
-            let mut max_index = i;
-            for k in i..m {
-                if origin
-                    .get(k, j)?
-                    .abs()
-                    .epsilon_gt(&origin.get(max_index, j)?.abs())
-                {
-                    max_index = k;
-                }
-            }
+            let max_index = origin.argmax_abs_in_column(j, i)?;
+            let max = origin.get(max_index, j)?.abs();
+
+            // if it is not zero, swap the row to the pivot unless the current pivot is large enough
+            if !max.is_zero() {
+                let current = origin.get(i, j)?.abs();
 
-            // if it is not zero, swap the row to the pivot
-            if !origin.get(max_index, j)?.is_zero() {
-                if max_index != i {
+                if max_index != i && (current.is_zero() || current.epsilon_lt(&(max * threshold))) {
                     swap_count += 1;
 
                     origin.swap_rows(i, max_index)?;
@@ -150,11 +218,11 @@ impl Matrix {
         Ok((origin, output, swap_count))
     }
 
-    fn _to_rref(&self, apply_to: Option<Self>) -> Result<(Self, Self)> {
+    fn _to_rref(&self, apply_to: Option<Self>, threshold: f64) -> Result<(Self, Self)> {
         let m = self.rows_number;
         let n = self.cols_number;
 
-        let (mut origin, mut output, _) = self._row_echelon(apply_to)?;
+        let (mut origin, mut output, _) = self._row_echelon(apply_to, threshold)?;
 
         for i in (0..m).rev() {
             // find pivot in row_i
@@ -210,4 +278,52 @@ mod tests {
             0, 0, 0, 0, 0;
         ]));
     }
+
+    #[test]
+    fn to_rref_threshold() {
+        let m = matrix![
+            1, 2, 3;
+            4, 5, 6;
+            7, 8, 10;
+        ];
+
+        // no swaps at all, since the pivots never vanish
+        let (_, _, swap_count) = m._row_echelon(None, 0.0).unwrap();
+        assert_eq!(swap_count, 0);
+        assert!(m.to_rref_threshold(0.0).epsilon_equals(&m.to_rref()));
+
+        let (echelon, _, swap_count) = m._row_echelon(None, 1.0).unwrap();
+        assert_eq!(swap_count, m.row_echelon().unwrap().1);
+        assert!(echelon.epsilon_equals(&m.row_echelon().unwrap().0));
+        assert!(m.to_rref_threshold(1.0).epsilon_equals(&m.to_rref()));
+
+        // a zero pivot is always swapped
+        let m = matrix![
+            0, 1;
+            1, 0;
+        ];
+        let (_, _, swap_count) = m._row_echelon(None, 0.0).unwrap();
+        assert_eq!(swap_count, 1);
+        assert!(m
+            .to_rref_threshold(0.0)
+            .epsilon_equals(&Matrix::identity(2)));
+    }
+
+    #[test]
+    fn argmax_abs_in_column() {
+        let m = matrix![
+            1, 0;
+            -3, 0;
+            8, 0;
+            -12, 0;
+            4, 0;
+        ];
+
+        assert_eq!(m.argmax_abs_in_column(0, 0).unwrap(), 3);
+        assert_eq!(m.argmax_abs_in_column(0, 4).unwrap(), 4);
+        assert_eq!(m.argmax_abs_in_column(1, 1).unwrap(), 1);
+
+        m.argmax_abs_in_column(2, 0).unwrap_err();
+        m.argmax_abs_in_column(0, 5).unwrap_err();
+    }
 }
@@ -0,0 +1,146 @@
+use crate::{matrix, Matrix, MatrixElement};
+
+impl Matrix {
+    /// Returns the matrix rotating 2D vectors counterclockwise by the angle `theta`, in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let r = Matrix::rotation_2d(std::f64::consts::FRAC_PI_2);
+    ///
+    /// assert!(r.multiply_vector(&vector![1, 0]).unwrap().epsilon_equals(&vector![0, 1]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Rotation matrix](https://en.wikipedia.org/wiki/Rotation_matrix)
+    /// * [`Matrix::givens`]
+    pub fn rotation_2d(theta: f64) -> Self {
+        let (s, c) = theta.sin_cos();
+
+        matrix![
+            c, -s;
+            s, c;
+        ]
+    }
+
+    /// Returns the matrix rotating 3D vectors by the angle `theta` around the x-axis, in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let r = Matrix::rotation_3d_x(std::f64::consts::FRAC_PI_2);
+    ///
+    /// assert!(r.multiply_vector(&vector![0, 1, 0]).unwrap().epsilon_equals(&vector![0, 0, 1]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Rotation matrix](https://en.wikipedia.org/wiki/Rotation_matrix#Basic_3D_rotations)
+    pub fn rotation_3d_x(theta: f64) -> Self {
+        let (s, c) = theta.sin_cos();
+
+        matrix![
+            1, 0, 0;
+            0, c, -s;
+            0, s, c;
+        ]
+    }
+
+    /// Returns the matrix rotating 3D vectors by the angle `theta` around the y-axis, in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let r = Matrix::rotation_3d_y(std::f64::consts::FRAC_PI_2);
+    ///
+    /// assert!(r.multiply_vector(&vector![0, 0, 1]).unwrap().epsilon_equals(&vector![1, 0, 0]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Rotation matrix](https://en.wikipedia.org/wiki/Rotation_matrix#Basic_3D_rotations)
+    pub fn rotation_3d_y(theta: f64) -> Self {
+        let (s, c) = theta.sin_cos();
+
+        matrix![
+            c, 0, s;
+            0, 1, 0;
+            -s, 0, c;
+        ]
+    }
+
+    /// Returns the matrix rotating 3D vectors by the angle `theta` around the z-axis, in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let r = Matrix::rotation_3d_z(std::f64::consts::FRAC_PI_2);
+    ///
+    /// assert!(r.multiply_vector(&vector![1, 0, 0]).unwrap().epsilon_equals(&vector![0, 1, 0]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Rotation matrix](https://en.wikipedia.org/wiki/Rotation_matrix#Basic_3D_rotations)
+    pub fn rotation_3d_z(theta: f64) -> Self {
+        let (s, c) = theta.sin_cos();
+
+        matrix![
+            c, -s, 0;
+            s, c, 0;
+            0, 0, 1;
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{vector, Matrix, MatrixElement, Vector};
+
+    #[test]
+    fn rotations_are_proper() {
+        let theta = 0.83;
+
+        for r in [
+            Matrix::rotation_2d(theta),
+            Matrix::rotation_3d_x(theta),
+            Matrix::rotation_3d_y(theta),
+            Matrix::rotation_3d_z(theta),
+        ] {
+            assert!(r.is_orthogonal());
+            assert!(r.det().unwrap().is_one());
+        }
+    }
+
+    #[test]
+    fn rotate_known_vectors() {
+        let quarter = std::f64::consts::FRAC_PI_4;
+        let half_sqrt_2 = std::f64::consts::FRAC_1_SQRT_2;
+
+        assert!(Matrix::rotation_2d(quarter)
+            .multiply_vector(&vector![1, 0])
+            .unwrap()
+            .epsilon_equals(&vector![half_sqrt_2, half_sqrt_2]));
+
+        let half = std::f64::consts::PI;
+        let v = vector![1, 2, 3];
+
+        assert!(Matrix::rotation_3d_x(half)
+            .multiply_vector(&v)
+            .unwrap()
+            .epsilon_equals(&vector![1, -2, -3]));
+        assert!(Matrix::rotation_3d_y(half)
+            .multiply_vector(&v)
+            .unwrap()
+            .epsilon_equals(&vector![-1, 2, -3]));
+        assert!(Matrix::rotation_3d_z(half)
+            .multiply_vector(&v)
+            .unwrap()
+            .epsilon_equals(&vector![-1, -2, 3]));
+    }
+}
@@ -1,6 +1,6 @@
 use std::ops::{Div, Mul};
 
-use crate::{Matrix, MatrixElement, Result};
+use crate::{Error, Matrix, MatrixElement, Result, Vector};
 
 impl Matrix {
     /// Scales the matrix by a scalar.
@@ -52,6 +52,86 @@ impl Matrix {
 
         Ok(())
     }
+
+    /// Returns a new matrix with row `i` scaled by `factors[i]`, which is `diag(factors) * self`.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the length of `factors` is different from the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let m = matrix![
+    ///     1, 2;
+    ///     3, 4;
+    /// ];
+    ///
+    /// assert!(m.scale_rows_by(&vector![2, -1]).unwrap().epsilon_equals(&matrix![
+    ///     2, 4;
+    ///     -3, -4;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::scale_cols_by`]
+    /// * [`Matrix::scale_row`]
+    pub fn scale_rows_by(&self, factors: &Vector) -> Result<Self> {
+        if factors.len() != self.rows_number {
+            return Err(Error::InvalidOperation(
+                "Factors length must be equal to rows to scale rows",
+            ));
+        }
+
+        let mut result = self.clone();
+        for (i, factor) in factors.clone().into_iter().enumerate() {
+            result.scale_row(i, factor)?;
+        }
+
+        Ok(result)
+    }
+
+    /// Returns a new matrix with column `j` scaled by `factors[j]`, which is `self * diag(factors)`.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the length of `factors` is different from the number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let m = matrix![
+    ///     1, 2;
+    ///     3, 4;
+    /// ];
+    ///
+    /// assert!(m.scale_cols_by(&vector![2, -1]).unwrap().epsilon_equals(&matrix![
+    ///     2, -2;
+    ///     6, -4;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::scale_rows_by`]
+    /// * [`Matrix::scale_col`]
+    pub fn scale_cols_by(&self, factors: &Vector) -> Result<Self> {
+        if factors.len() != self.cols_number {
+            return Err(Error::InvalidOperation(
+                "Factors length must be equal to columns to scale columns",
+            ));
+        }
+
+        let mut result = self.clone();
+        for (j, factor) in factors.clone().into_iter().enumerate() {
+            result.scale_col(j, factor)?;
+        }
+
+        Ok(result)
+    }
 }
 
 impl Mul<MatrixElement> for Matrix {
@@ -107,7 +187,7 @@ impl_mul!(i8, i16, i32, i64, f32, f64);
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::matrix;
+    use crate::{matrix, vector};
 
     #[test]
     fn scale() {
@@ -126,4 +206,36 @@ mod tests {
         let m: Matrix = m2 / 2;
         assert!(m.epsilon_equals(&m1));
     }
+
+    #[test]
+    fn scale_rows_and_cols_by() {
+        let m = matrix![
+            1, 2, 3;
+            4, 5, 6;
+        ];
+        let diag = |factors: &Vector| {
+            Matrix::from_fn(factors.len(), factors.len(), |i, j| {
+                if i == j {
+                    factors[i]
+                } else {
+                    MatrixElement::zero()
+                }
+            })
+        };
+
+        let rows = vector![0.5, -3];
+        assert!(m
+            .scale_rows_by(&rows)
+            .unwrap()
+            .epsilon_equals(&(diag(&rows) * m.clone())));
+
+        let cols = vector![2, 0, -1.5];
+        assert!(m
+            .scale_cols_by(&cols)
+            .unwrap()
+            .epsilon_equals(&(m.clone() * diag(&cols))));
+
+        m.scale_rows_by(&cols).unwrap_err();
+        m.scale_cols_by(&rows).unwrap_err();
+    }
 }
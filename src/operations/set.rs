@@ -8,7 +8,7 @@ impl Matrix {
         let value: MatrixElement = value.into();
 
         // To avoid negative zero
-        self.elements[row][col] = if value.is_zero() {
+        self.elements[row * self.cols_number + col] = if value.is_zero() {
             MatrixElement::zero()
         } else {
             value
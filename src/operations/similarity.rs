@@ -0,0 +1,95 @@
+use crate::{Error, Matrix, Result};
+
+impl Matrix {
+    /// Returns the similarity transform `P⁻¹ * A * P`, which represents the same linear map as `A`
+    /// in the basis given by the columns of `P`.
+    ///
+    /// Similar matrices have the same eigenvalues, trace and determinant.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrices are not square, if their sizes do not match, or if `P`
+    /// cannot be inverted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let a = matrix![
+    ///     2, 1;
+    ///     0, 3;
+    /// ];
+    ///
+    /// // the columns are eigenvectors of `a`
+    /// let p = matrix![
+    ///     1, 1;
+    ///     0, 1;
+    /// ];
+    ///
+    /// assert!(a.similarity_transform(&p).unwrap().epsilon_equals(&matrix![
+    ///     2, 0;
+    ///     0, 3;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Matrix similarity](https://en.wikipedia.org/wiki/Matrix_similarity)
+    pub fn similarity_transform(&self, p: &Matrix) -> Result<Self> {
+        self.assert_square("Only square matrices have similarity transforms")?;
+
+        if p.rows_number != self.rows_number || p.cols_number != self.cols_number {
+            return Err(Error::InvalidOperation(
+                "Matrix dimensions must match for a similarity transform",
+            ));
+        }
+
+        p.inverse()?.multiply(&self.multiply(p)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, Matrix, MatrixElement};
+
+    #[test]
+    fn similarity_transform_invariants() {
+        let a = matrix![
+            4, -2, 1;
+            3, 6, -4;
+            2, 1, 8;
+        ];
+        let p = matrix![
+            1, 2, 0;
+            0, 1, 3;
+            1, 0, 1;
+        ];
+
+        let b = a.similarity_transform(&p).unwrap();
+
+        assert!(b.trace().unwrap().epsilon_equals(&a.trace().unwrap()));
+        assert!(b.det().unwrap().epsilon_equals(&a.det().unwrap()));
+    }
+
+    #[test]
+    fn similarity_transform_diagonalizes() {
+        let a = matrix![
+            4, 1;
+            2, 3;
+        ];
+
+        // eigenvalues 5 and 2 with eigenvectors (1, 1) and (1, -2)
+        let p = matrix![
+            1, 1;
+            1, -2;
+        ];
+
+        assert!(a.similarity_transform(&p).unwrap().epsilon_equals(&matrix![
+            5, 0;
+            0, 2;
+        ]));
+
+        a.similarity_transform(&matrix![1, 2; 2, 4]).unwrap_err();
+        a.similarity_transform(&Matrix::identity(3)).unwrap_err();
+    }
+}
@@ -0,0 +1,437 @@
+use crate::{Error, Matrix, MatrixElement, Result, Vector};
+
+impl Matrix {
+    /// Solves the linear system `self * x == b`.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square, if the length of `b` is different from the
+    /// number of rows, or if the system does not have a unique solution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let m = matrix![
+    ///     2, 1, 1;
+    ///     4, -6, 0;
+    ///     -2, 7, 2;
+    /// ];
+    ///
+    /// assert!(m.solve(&vector![5, -2, 9]).unwrap().epsilon_equals(&vector![1, 1, 2]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [System of linear equations](https://en.wikipedia.org/wiki/System_of_linear_equations)
+    /// * [`Matrix::to_rref_apply_to`]
+    /// * [`Matrix::lu_factor`]
+    pub fn solve(&self, b: &Vector) -> Result<Vector> {
+        self.assert_square("Only square matrices can be solved")?;
+
+        if b.len() != self.rows_number {
+            return Err(Error::InvalidOperation(
+                "Vector length must be equal to matrix rows to solve",
+            ));
+        }
+
+        // [ A | b ] -> [ I | x ]
+        let (origin, applied) = self.to_rref_apply_to(b.as_column()?)?;
+
+        if !origin.epsilon_equals(&Self::identity(self.rows_number)) {
+            return Err(Error::InvalidOperation(
+                "The system does not have a unique solution",
+            ));
+        }
+
+        applied.get_col(0)
+    }
+
+    /// Solves the linear system `self * x == b` for any matrix, returning a particular solution
+    /// together with a basis of the null space.
+    ///
+    /// The full solution set is `particular + span(basis)`. Free variables are set to zero in the
+    /// particular solution, and the basis is empty if the solution is unique.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the length of `b` is different from the number of rows, or if the system
+    /// is inconsistent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let m = matrix![
+    ///     1, 1, 0;
+    ///     0, 0, 1;
+    /// ];
+    ///
+    /// let (particular, basis) = m.solve_general(&vector![3, 2]).unwrap();
+    ///
+    /// assert!(particular.epsilon_equals(&vector![3, 0, 2]));
+    /// assert!(basis[0].epsilon_equals(&vector![-1, 1, 0]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [System of linear equations](https://en.wikipedia.org/wiki/System_of_linear_equations#Solution_set)
+    /// * [`Matrix::solve`]
+    /// * [`Matrix::null_space`]
+    pub fn solve_general(&self, b: &Vector) -> Result<(Vector, Vec<Vector>)> {
+        if b.len() != self.rows_number {
+            return Err(Error::InvalidOperation(
+                "Vector length must be equal to matrix rows to solve",
+            ));
+        }
+
+        // [ A | b ] -> [ rref(A) | c ]
+        let (origin, applied) = self.to_rref_apply_to(b.as_column()?)?;
+
+        let mut particular = Vector::zero(self.cols_number);
+
+        for (i, row) in origin.as_rows().into_iter().enumerate() {
+            match row.into_iter().position(|element| !element.is_zero()) {
+                Some(pivot) => particular.data[pivot] = applied[(i, 0)],
+                // a zero row of A must be matched by a zero entry of b
+                None if !applied[(i, 0)].is_zero() => {
+                    return Err(Error::InvalidOperation("The system is inconsistent"));
+                }
+                None => {}
+            }
+        }
+
+        Ok((particular, self.null_space()))
+    }
+
+    /// Checks if the linear system `self * x == b` has at least one solution.
+    ///
+    /// By the Rouché–Capelli theorem, this is the case if and only if the rank of the matrix is
+    /// equal to the rank of the augmented matrix `[ A | b ]`.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the length of `b` is different from the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let m = matrix![
+    ///     1, 2;
+    ///     2, 4;
+    /// ];
+    ///
+    /// assert!(m.system_is_consistent(&vector![1, 2]).unwrap());
+    /// assert!(!m.system_is_consistent(&vector![1, 3]).unwrap());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Rouché–Capelli theorem](https://en.wikipedia.org/wiki/Rouch%C3%A9%E2%80%93Capelli_theorem)
+    /// * [`Matrix::solve_general`]
+    pub fn system_is_consistent(&self, b: &Vector) -> Result<bool> {
+        if b.len() != self.rows_number {
+            return Err(Error::InvalidOperation(
+                "Vector length must be equal to matrix rows to solve",
+            ));
+        }
+
+        let augmented = self.insert_col(self.cols_number, b)?;
+
+        Ok(self.rank() == augmented.rank())
+    }
+
+    /// Solves the Tikhonov-regularized least-squares problem, which is the linear system
+    /// `(Aᵀ A + λ I) x == Aᵀ b`.
+    ///
+    /// With `lambda = 0` these are the normal equations of ordinary least squares. A positive
+    /// `lambda` keeps the system solvable and damps the solution when `A` is ill-conditioned.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if `lambda` is negative, infinite or `NaN`, if the length of `b` is different
+    /// from the number of rows, or if the regularized system does not have a unique solution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let m = matrix![
+    ///     1, 0;
+    ///     0, 1;
+    /// ];
+    ///
+    /// // (I + I) x = b
+    /// assert!(m.ridge_solve(&vector![2, 4], 1.0).unwrap().epsilon_equals(&vector![1, 2]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Ridge regression](https://en.wikipedia.org/wiki/Ridge_regression#Tikhonov_regularization)
+    /// * [`Matrix::solve`]
+    pub fn ridge_solve(&self, b: &Vector, lambda: f64) -> Result<Vector> {
+        if !lambda.is_finite() || lambda < 0.0 {
+            return Err(Error::InvalidOperation(
+                "The regularization parameter must be finite and not negative",
+            ));
+        }
+
+        if b.len() != self.rows_number {
+            return Err(Error::InvalidOperation(
+                "Vector length must be equal to matrix rows to solve",
+            ));
+        }
+
+        let transpose = self.transpose();
+
+        let regularized = transpose
+            .multiply(self)?
+            .add_s(&Self::identity(self.cols_number).scale(lambda))?;
+
+        regularized.solve(&transpose.multiply_vector(b)?)
+    }
+
+    /// Solves the linear system `self * X == B` for every column of `B` at once.
+    ///
+    /// The LUP factorization of the matrix is computed once and reused for each column.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square, if `B` has a different number of rows, or if
+    /// the matrix cannot be inverted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     2, 1;
+    ///     1, 3;
+    /// ];
+    /// let b = matrix![
+    ///     3, 1;
+    ///     4, -2;
+    /// ];
+    ///
+    /// let x = m.solve_matrix(&b).unwrap();
+    ///
+    /// assert!((m * x).epsilon_equals(&b));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::solve`]
+    /// * [`Matrix::lu_factor`]
+    pub fn solve_matrix(&self, b: &Matrix) -> Result<Matrix> {
+        if b.rows_number != self.rows_number {
+            return Err(Error::InvalidOperation(
+                "Matrix rows must be equal to solve for multiple right-hand sides",
+            ));
+        }
+
+        let lu = self.lu_factor()?;
+
+        // `from_cols` needs at least one column
+        if b.cols_number == 0 {
+            return Ok(Matrix::zero(self.cols_number, 0));
+        }
+
+        let cols = b
+            .as_cols()
+            .iter()
+            .map(|col| lu.solve(col))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Matrix::from_cols(cols))
+    }
+
+    /// Returns the residual norm `||b - self * x||₂` of an approximate solution `x`.
+    ///
+    /// It is the stopping criterion of iterative solvers.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the length of `x` is different from the number of columns, or if the
+    /// length of `b` is different from the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let m = matrix![
+    ///     2, 1;
+    ///     1, 3;
+    /// ];
+    ///
+    /// assert!(m.residual_norm(&vector![1, 1], &vector![3, 4]).unwrap().is_zero());
+    /// assert!(m.residual_norm(&vector![1, 0], &vector![3, 4]).unwrap().epsilon_equals(&10.0_f64.sqrt()));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::solve`]
+    pub fn residual_norm(&self, x: &Vector, b: &Vector) -> Result<MatrixElement> {
+        if b.len() != self.rows_number {
+            return Err(Error::InvalidOperation(
+                "Vector length must be equal to matrix rows to compute the residual",
+            ));
+        }
+
+        Ok(b.subtract(&self.multiply_vector(x)?).norm())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, vector, MatrixElement};
+
+    #[test]
+    fn solve() {
+        let m = matrix![
+            0, 1, 2;
+            1, 0, 3;
+            4, -3, 8;
+        ];
+        let b = vector![1, -2, 3.5];
+
+        let x = m.solve(&b).unwrap();
+
+        assert!(m.multiply_vector(&x).unwrap().epsilon_equals(&b));
+    }
+
+    #[test]
+    fn solve_singular() {
+        let m = matrix![
+            1, 3, 4;
+            2, 5, 6;
+            3, 7, 8;
+        ];
+
+        m.solve(&vector![1, 2, 3]).unwrap_err();
+        matrix![1, 2; 3, 4].solve(&vector![1, 2, 3]).unwrap_err();
+    }
+
+    #[test]
+    fn solve_general() {
+        let m = matrix![
+            1, 2, 1, 1;
+            2, 4, 0, 6;
+        ];
+        let b = vector![3, 2];
+
+        let (particular, basis) = m.solve_general(&b).unwrap();
+
+        assert_eq!(basis.len(), 2);
+        for t in [-3.5, 0.0, 1.0, 42.0] {
+            let x = particular.add(&basis[0].scale(t));
+
+            assert!(m.multiply_vector(&x).unwrap().epsilon_equals(&b));
+        }
+
+        let inconsistent = matrix![
+            1, 2;
+            2, 4;
+        ];
+        inconsistent.solve_general(&vector![1, 3]).unwrap_err();
+        inconsistent.solve_general(&vector![1, 2, 3]).unwrap_err();
+    }
+
+    #[test]
+    fn system_is_consistent() {
+        let m = matrix![
+            1, 2, 1;
+            2, 4, 0;
+            3, 6, 1;
+        ];
+
+        assert!(m.system_is_consistent(&vector![3, 2, 5]).unwrap());
+        assert!(!m.system_is_consistent(&vector![3, 2, 6]).unwrap());
+
+        m.system_is_consistent(&vector![1, 2]).unwrap_err();
+    }
+
+    #[test]
+    fn ridge_solve() {
+        // fit y = c + d * x through (0, 1), (1, 2) and (2, 4)
+        let m = matrix![
+            1, 0;
+            1, 1;
+            1, 2;
+        ];
+        let b = vector![1, 2, 4];
+
+        // the least-squares solution of the normal equations is (5/6, 3/2)
+        let least_squares = vector![5.0 / 6.0, 1.5];
+
+        assert!(m
+            .ridge_solve(&b, 0.0)
+            .unwrap()
+            .epsilon_equals(&least_squares));
+        assert!(m
+            .ridge_solve(&b, 1e-10)
+            .unwrap()
+            .epsilon_equals(&least_squares));
+
+        // stronger regularization shrinks the solution
+        let damped = m.ridge_solve(&b, 10.0).unwrap();
+        assert!(damped.norm() < least_squares.norm());
+
+        m.ridge_solve(&b, -1.0).unwrap_err();
+        m.ridge_solve(&b, f64::NAN).unwrap_err();
+        m.ridge_solve(&b, f64::INFINITY).unwrap_err();
+        m.ridge_solve(&vector![1, 2], 1.0).unwrap_err();
+    }
+
+    #[test]
+    fn solve_matrix() {
+        let m = matrix![
+            0, 1, 2;
+            1, 0, 3;
+            4, -3, 8;
+        ];
+        let b = matrix![
+            1, 0;
+            -2, 5;
+            3.5, -1;
+        ];
+
+        let x = m.solve_matrix(&b).unwrap();
+
+        for j in 0..2 {
+            assert!(x
+                .get_col(j)
+                .unwrap()
+                .epsilon_equals(&m.solve(&b.get_col(j).unwrap()).unwrap()));
+        }
+
+        let x = m.solve_matrix(&Matrix::zero(3, 0)).unwrap();
+        assert_eq!((x.rows_number, x.cols_number), (3, 0));
+
+        m.solve_matrix(&matrix![1, 2; 3, 4]).unwrap_err();
+        matrix![1, 2; 2, 4]
+            .solve_matrix(&matrix![1; 2])
+            .unwrap_err();
+    }
+
+    #[test]
+    fn residual_norm() {
+        let m = matrix![
+            0, 1, 2;
+            1, 0, 3;
+            4, -3, 8;
+        ];
+        let b = vector![1, -2, 3.5];
+        let x = m.solve(&b).unwrap();
+
+        assert!(m.residual_norm(&x, &b).unwrap().is_zero());
+        assert!(m
+            .residual_norm(&x.add(&vector![0.1, 0, 0]), &b)
+            .unwrap()
+            .is_positive());
+
+        m.residual_norm(&vector![1, 2], &b).unwrap_err();
+        m.residual_norm(&x, &vector![1, 2]).unwrap_err();
+    }
+}
@@ -0,0 +1,91 @@
+use std::cmp::Ordering;
+
+use crate::{Matrix, Vector};
+
+impl Matrix {
+    /// Returns a copy of the matrix with the rows sorted in ascending order of the given key.
+    ///
+    /// The sort is stable. Keys that cannot be compared with themselves (such as `NaN`) are
+    /// treated as greater than any other key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     3, 1;
+    ///     1, 2;
+    ///     2, 3;
+    /// ];
+    ///
+    /// assert!(m.sort_rows_by(|row| row[0]).epsilon_equals(&matrix![
+    ///     1, 2;
+    ///     2, 3;
+    ///     3, 1;
+    /// ]));
+    /// ```
+    pub fn sort_rows_by<K: PartialOrd, F: Fn(&Vector) -> K>(&self, key: F) -> Self {
+        let mut rows: Vec<(K, Vector)> = self
+            .as_rows()
+            .into_iter()
+            .map(|row| (key(&row), row))
+            .collect();
+
+        rows.sort_by(|(a, _), (b, _)| _compare_keys(a, b));
+
+        rows.into_iter().map(|(_, row)| row).collect()
+    }
+}
+
+fn _compare_keys<K: PartialOrd>(a: &K, b: &K) -> Ordering {
+    if let Some(ordering) = a.partial_cmp(b) {
+        return ordering;
+    }
+
+    let a_is_nan = a.partial_cmp(a).is_none();
+    let b_is_nan = b.partial_cmp(b).is_none();
+
+    match (a_is_nan, b_is_nan) {
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        _ => Ordering::Equal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, Matrix, MatrixElement};
+
+    #[test]
+    fn sort_rows_by_leading_entry() {
+        let m = matrix![
+            4, 0, 1;
+            -1, 5, 2;
+            4, 9, 3;
+            0, 1, 4;
+        ];
+
+        // stable: the two rows starting with `4` keep their order
+        assert!(m.sort_rows_by(|row| row[0]).epsilon_equals(&matrix![
+            -1, 5, 2;
+            0, 1, 4;
+            4, 0, 1;
+            4, 9, 3;
+        ]));
+    }
+
+    #[test]
+    fn sort_rows_by_nan_last() {
+        let m = Matrix::new(vec![
+            vec![MatrixElement::new(f64::NAN), MatrixElement::new(1.0)],
+            vec![MatrixElement::new(2.0), MatrixElement::new(2.0)],
+            vec![MatrixElement::new(-3.0), MatrixElement::new(3.0)],
+        ]);
+
+        let sorted = m.sort_rows_by(|row| row[0]);
+
+        assert!(sorted.get(0, 1).unwrap().epsilon_equals(&3));
+        assert!(sorted.get(1, 1).unwrap().epsilon_equals(&2));
+        assert!(sorted.get(2, 1).unwrap().epsilon_equals(&1));
+    }
+}
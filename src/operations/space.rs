@@ -0,0 +1,133 @@
+use crate::{Matrix, MatrixElement, Vector};
+
+impl Matrix {
+    /// Returns a basis of the column space (range) of the matrix, which consists of the columns of
+    /// the original matrix at the pivot positions of its reduced row echelon form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let m = matrix![
+    ///     1, 2, 0;
+    ///     2, 4, 1;
+    /// ];
+    ///
+    /// let basis = m.column_space();
+    ///
+    /// assert_eq!(basis.len(), 2);
+    /// assert!(basis[0].epsilon_equals(&vector![1, 2]));
+    /// assert!(basis[1].epsilon_equals(&vector![0, 1]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Row and column spaces](https://en.wikipedia.org/wiki/Row_and_column_spaces)
+    /// * [`Matrix::to_rref`]
+    /// * [`Matrix::rank`]
+    pub fn column_space(&self) -> Vec<Vector> {
+        let cols = self.as_cols();
+
+        Self::_pivot_cols(&self.to_rref())
+            .into_iter()
+            .map(|col| cols[col].clone())
+            .collect()
+    }
+
+    /// Returns a basis of the null space (kernel) of the matrix, which is the set of vectors `x`
+    /// with `self * x == 0`.
+    ///
+    /// There is one basis vector for every free column of the reduced row echelon form, so the
+    /// basis is empty if the columns are linearly independent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let m = matrix![
+    ///     1, 2;
+    ///     2, 4;
+    /// ];
+    ///
+    /// let basis = m.null_space();
+    ///
+    /// assert_eq!(basis.len(), 1);
+    /// assert!(basis[0].epsilon_equals(&vector![-2, 1]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Kernel](https://en.wikipedia.org/wiki/Kernel_(linear_algebra))
+    /// * [`Matrix::column_space`]
+    pub fn null_space(&self) -> Vec<Vector> {
+        let n = self.cols_number;
+        let rref = self.to_rref();
+        let pivots = Self::_pivot_cols(&rref);
+
+        (0..n)
+            .filter(|col| !pivots.contains(col))
+            .map(|free| {
+                let mut vector = Vector::zero(n);
+                vector.data[free] = MatrixElement::one();
+
+                // every pivot variable is determined by the free variables in its row
+                for (row, &pivot) in pivots.iter().enumerate() {
+                    vector.data[pivot] = rref[(row, free)].negate();
+                }
+
+                vector
+            })
+            .collect()
+    }
+
+    /// Returns the index of the leading column of every non-zero row of a matrix in reduced row
+    /// echelon form.
+    fn _pivot_cols(rref: &Self) -> Vec<usize> {
+        rref.as_rows()
+            .into_iter()
+            .filter_map(|row| row.into_iter().position(|element| !element.is_zero()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, Matrix, MatrixElement};
+
+    #[test]
+    fn column_space() {
+        let m = matrix![
+            1, 2, 3, 4;
+            2, 4, 7, 9;
+            3, 6, 10, 13;
+        ];
+
+        let basis = m.column_space();
+
+        assert_eq!(basis.len(), m.rank());
+        assert!(basis[0].epsilon_equals(&m.get_col(0).unwrap()));
+        assert!(basis[1].epsilon_equals(&m.get_col(2).unwrap()));
+
+        // the basis is linearly independent
+        assert_eq!(Matrix::from_cols(basis).rank(), 2);
+    }
+
+    #[test]
+    fn null_space() {
+        let m = matrix![
+            1, 2, 3, 4;
+            2, 4, 7, 9;
+            3, 6, 10, 13;
+        ];
+
+        let basis = m.null_space();
+
+        assert_eq!(basis.len(), m.cols_number - m.rank());
+        for vector in &basis {
+            assert!(m.multiply_vector(vector).unwrap().is_zero());
+        }
+        assert_eq!(Matrix::from_cols(basis).rank(), 2);
+
+        assert!(Matrix::identity(3).null_space().is_empty());
+    }
+}
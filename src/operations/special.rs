@@ -0,0 +1,206 @@
+use crate::{Error, Matrix, MatrixElement, Result};
+
+impl Matrix {
+    /// Returns the companion matrix of a polynomial, given its coefficients from the highest
+    /// degree to the lowest.
+    ///
+    /// The coefficients are divided by the leading one, so that the polynomial is monic. The
+    /// eigenvalues of the companion matrix are the roots of the polynomial.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the polynomial has degree zero or if the leading coefficient is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// // 2x² - 6x + 4 = 2(x - 1)(x - 2)
+    /// let coefficients = [2, -6, 4].map(MatrixElement::from);
+    ///
+    /// assert!(Matrix::companion(&coefficients).unwrap().epsilon_equals(&matrix![
+    ///     0, -2;
+    ///     1, 3;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Companion matrix](https://en.wikipedia.org/wiki/Companion_matrix)
+    /// * [`Matrix::characteristic_polynomial`]
+    pub fn companion(coeffs: &[MatrixElement]) -> Result<Self> {
+        if coeffs.len() < 2 {
+            return Err(Error::InvalidOperation(
+                "Polynomial must have a degree of at least one",
+            ));
+        }
+
+        let leading = coeffs[0];
+        if leading.is_zero() {
+            return Err(Error::InvalidOperation(
+                "Leading coefficient must not be zero",
+            ));
+        }
+
+        let n = coeffs.len() - 1;
+
+        Ok(Matrix::from_fn(n, n, |i, j| {
+            if j == n - 1 {
+                // the last column holds -c₀, -c₁, ..., -cₙ₋₁
+                (coeffs[n - i] / leading).negate()
+            } else if i == j + 1 {
+                MatrixElement::one()
+            } else {
+                MatrixElement::zero()
+            }
+        }))
+    }
+
+    /// Returns the Hilbert matrix of the given size, whose element at `(i, j)` is `1 / (i + j + 1)`.
+    ///
+    /// Hilbert matrices are notoriously ill-conditioned, which makes them a good test case for
+    /// numerical algorithms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// assert!(Matrix::hilbert(2).epsilon_equals(&matrix![
+    ///     1, 0.5;
+    ///     0.5, 1.0 / 3.0;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Hilbert matrix](https://en.wikipedia.org/wiki/Hilbert_matrix)
+    pub fn hilbert(n: usize) -> Self {
+        Matrix::from_fn(n, n, |i, j| {
+            MatrixElement::one() / MatrixElement::from((i + j + 1) as f64)
+        })
+    }
+
+    /// Returns the matrix of the 5-point finite difference Laplacian on an `n × n` grid, with
+    /// Dirichlet boundary conditions.
+    ///
+    /// The result has size `n² × n²` and is the Kronecker sum `I ⊗ T + T ⊗ I`, where `T` is the
+    /// `n × n` second difference matrix with `2` on the diagonal and `-1` next to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// assert!(Matrix::poisson_2d(2).epsilon_equals(&matrix![
+    ///     4, -1, -1, 0;
+    ///     -1, 4, 0, -1;
+    ///     -1, 0, 4, -1;
+    ///     0, -1, -1, 4;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Discrete Poisson equation](https://en.wikipedia.org/wiki/Discrete_Poisson_equation)
+    /// * [`Matrix::kronecker_product`]
+    pub fn poisson_2d(n: usize) -> Self {
+        let second_difference = Matrix::from_fn(n, n, |i, j| {
+            if i == j {
+                MatrixElement::from(2)
+            } else if i.abs_diff(j) == 1 {
+                MatrixElement::from(-1)
+            } else {
+                MatrixElement::zero()
+            }
+        });
+        let identity = Matrix::identity(n);
+
+        identity.kronecker_product(&second_difference)
+            + second_difference.kronecker_product(&identity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, MatrixElement};
+
+    #[test]
+    fn companion() {
+        // 3x⁴ - 6x³ + 1.5x² + 9x - 12
+        let coeffs = [3.0, -6.0, 1.5, 9.0, -12.0].map(MatrixElement::from);
+
+        let polynomial = Matrix::companion(&coeffs)
+            .unwrap()
+            .characteristic_polynomial()
+            .unwrap();
+
+        for (actual, expected) in polynomial.iter().zip(coeffs.iter()) {
+            assert!(actual.epsilon_equals(&(*expected / coeffs[0])));
+        }
+
+        Matrix::companion(&[MatrixElement::one()]).unwrap_err();
+        Matrix::companion(&[0, 1, 2].map(MatrixElement::from)).unwrap_err();
+    }
+
+    #[test]
+    fn hilbert() {
+        let h = Matrix::hilbert(3);
+
+        for (i, row) in [
+            [1.0, 1.0 / 2.0, 1.0 / 3.0],
+            [1.0 / 2.0, 1.0 / 3.0, 1.0 / 4.0],
+            [1.0 / 3.0, 1.0 / 4.0, 1.0 / 5.0],
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            for (j, expected) in row.into_iter().enumerate() {
+                assert!(h.get(i, j).unwrap().epsilon_equals(&expected));
+            }
+        }
+
+        // the 2-norm condition number of the 3x3 Hilbert matrix is about 524
+        let singular_values = h.singular_values();
+        let condition_number = singular_values[0] / singular_values[2];
+
+        assert!(condition_number.value() > 500.0);
+    }
+
+    #[test]
+    fn poisson_2d() {
+        let n = 3;
+        let m = Matrix::poisson_2d(n);
+
+        assert_eq!(m.rows_number, n * n);
+        assert!(m.is_symmetric());
+        assert!(m
+            .diagonal_dominance_ratio()
+            .unwrap()
+            .epsilon_ge(&MatrixElement::one()));
+
+        // grid point (row, col) is coupled to its neighbours only
+        for p in 0..n * n {
+            for q in 0..n * n {
+                let (pr, pc) = (p / n, p % n);
+                let (qr, qc) = (q / n, q % n);
+
+                let expected = if p == q {
+                    4
+                } else if pr.abs_diff(qr) + pc.abs_diff(qc) == 1 {
+                    -1
+                } else {
+                    0
+                };
+
+                assert!(m.get(p, q).unwrap().epsilon_equals(&expected));
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{Error, Matrix, MatrixElement, Result};
+
+/// Below this size, [`Matrix::multiply_strassen`] falls back to [`Matrix::multiply`].
+const STRASSEN_THRESHOLD: usize = 64;
+
+impl Matrix {
+    /// Returns a new matrix that is the product of this matrix and another matrix, computed with
+    /// Strassen's algorithm.
+    ///
+    /// Both matrices are padded with zeros to the next power of two. Once the blocks are not larger
+    /// than 64, the naive [`Matrix::multiply`] is used instead.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the number of columns of this matrix is different from the number of rows of the other matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m1 = matrix![
+    ///     -1, 2;
+    ///     1 / 3, 4;
+    /// ];
+    ///
+    /// let m2 = matrix![
+    ///     1, 0.2;
+    ///     4, -5;
+    /// ];
+    ///
+    /// assert!((m1.multiply_strassen(&m2).unwrap()).epsilon_equals(&m1.multiply(&m2).unwrap()));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Strassen algorithm](https://en.wikipedia.org/wiki/Strassen_algorithm)
+    /// * [`Matrix::multiply`]
+    pub fn multiply_strassen(&self, other: &Self) -> Result<Self> {
+        if self.cols_number != other.rows_number {
+            return Err(Error::InvalidOperation(
+                "Matrix multiplication is only available for MxP * PxN",
+            ));
+        }
+
+        let size = self
+            .rows_number
+            .max(self.cols_number)
+            .max(other.cols_number)
+            .next_power_of_two();
+
+        if size <= STRASSEN_THRESHOLD {
+            return self.multiply(other);
+        }
+
+        let product = self._pad(size)._strassen(&other._pad(size));
+
+        Ok(product._block(0, 0, self.rows_number, other.cols_number))
+    }
+
+    /// Both matrices must be square with the same power-of-two size.
+    fn _strassen(&self, other: &Self) -> Self {
+        let n = self.rows_number;
+
+        if n <= STRASSEN_THRESHOLD {
+            return self.multiply(other).unwrap(); // INFO: safe to unwrap
+        }
+
+        let h = n / 2;
+
+        let (a11, a12, a21, a22) = (
+            self._block(0, 0, h, h),
+            self._block(0, h, h, h),
+            self._block(h, 0, h, h),
+            self._block(h, h, h, h),
+        );
+        let (b11, b12, b21, b22) = (
+            other._block(0, 0, h, h),
+            other._block(0, h, h, h),
+            other._block(h, 0, h, h),
+            other._block(h, h, h, h),
+        );
+
+        let m1 = a11._plus(&a22)._strassen(&b11._plus(&b22));
+        let m2 = a21._plus(&a22)._strassen(&b11);
+        let m3 = a11._strassen(&b12._minus(&b22));
+        let m4 = a22._strassen(&b21._minus(&b11));
+        let m5 = a11._plus(&a12)._strassen(&b22);
+        let m6 = a21._minus(&a11)._strassen(&b11._plus(&b12));
+        let m7 = a12._minus(&a22)._strassen(&b21._plus(&b22));
+
+        let c11 = m1._plus(&m4)._minus(&m5)._plus(&m7);
+        let c12 = m3._plus(&m5);
+        let c21 = m2._plus(&m4);
+        let c22 = m1._minus(&m2)._plus(&m3)._plus(&m6);
+
+        Self::from_fn(n, n, |i, j| match (i < h, j < h) {
+            (true, true) => c11[(i, j)],
+            (true, false) => c12[(i, j - h)],
+            (false, true) => c21[(i - h, j)],
+            (false, false) => c22[(i - h, j - h)],
+        })
+    }
+
+    /// Returns a `size x size` matrix with this matrix in the top left corner and zeros elsewhere.
+    fn _pad(&self, size: usize) -> Self {
+        Self::from_fn(size, size, |i, j| {
+            if i < self.rows_number && j < self.cols_number {
+                self[(i, j)]
+            } else {
+                MatrixElement::zero()
+            }
+        })
+    }
+
+    /// Returns the `rows x cols` block whose top left corner is at `(row, col)`.
+    fn _block(&self, row: usize, col: usize, rows: usize, cols: usize) -> Self {
+        Self::from_fn(rows, cols, |i, j| self[(row + i, col + j)])
+    }
+
+    fn _plus(&self, other: &Self) -> Self {
+        self.zip_map(other, |a, b| a + b).unwrap() // INFO: safe to unwrap
+    }
+
+    fn _minus(&self, other: &Self) -> Self {
+        self.zip_map(other, |a, b| a - b).unwrap() // INFO: safe to unwrap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn multiply_strassen() {
+        let m1 = Matrix::from_fn(65, 65, |i, j| {
+            MatrixElement::from(((i * 7 + j * 3) % 11) as f64 - 5.0)
+        });
+        let m2 = Matrix::from_fn(65, 65, |i, j| {
+            MatrixElement::from(((i * 5 + j * 13) % 17) as f64 / 4.0)
+        });
+
+        assert!(m1
+            .multiply_strassen(&m2)
+            .unwrap()
+            .epsilon_equals(&m1.multiply(&m2).unwrap()));
+    }
+
+    #[test]
+    fn multiply_strassen_rectangular() {
+        let m1 = Matrix::from_fn(70, 3, |i, j| MatrixElement::from((i + j) as f64));
+        let m2 = Matrix::from_fn(3, 66, |i, j| MatrixElement::from(i as f64 - j as f64));
+
+        let product = m1.multiply_strassen(&m2).unwrap();
+
+        assert_eq!(product.rows_number, 70);
+        assert_eq!(product.cols_number, 66);
+        assert!(product.epsilon_equals(&m1.multiply(&m2).unwrap()));
+    }
+
+    #[test]
+    fn multiply_strassen_wrong_size() {
+        let m1 = matrix![1, 2; 3, 4;];
+        let m2 = matrix![1, 2; 3, 4; 5, 6;];
+
+        m1.multiply_strassen(&m2).unwrap_err();
+    }
+}
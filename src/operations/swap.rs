@@ -26,7 +26,10 @@ impl Matrix {
         self.assert_index(row1, 0)?;
         self.assert_index(row2, 0)?;
 
-        self.elements.swap(row1, row2);
+        for j in 0..self.cols_number {
+            self.elements
+                .swap(row1 * self.cols_number + j, row2 * self.cols_number + j);
+        }
 
         Ok(())
     }
@@ -37,7 +40,8 @@ impl Matrix {
         self.assert_index(0, col2)?;
 
         for i in 0..self.rows_number {
-            self.elements[i].swap(col1, col2);
+            self.elements
+                .swap(i * self.cols_number + col1, i * self.cols_number + col2);
         }
 
         Ok(())
@@ -0,0 +1,174 @@
+use crate::{Error, Matrix, MatrixElement, Result, Vector};
+
+impl Matrix {
+    /// Returns the trace of the matrix, which is the sum of the elements on the main diagonal.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 2, 3;
+    ///     4, 5, 6;
+    ///     7, 8, 9;
+    /// ];
+    ///
+    /// assert!(m.trace().unwrap().epsilon_equals(&15));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Trace](https://en.wikipedia.org/wiki/Trace_(linear_algebra))
+    pub fn trace(&self) -> Result<MatrixElement> {
+        self.assert_square("Only square matrices have traces")?;
+
+        Ok(self
+            .diagonal_iter()
+            .fold(MatrixElement::zero(), |acc, x| acc + x))
+    }
+
+    /// Returns the trace of the inverse of the matrix without computing the full inverse.
+    ///
+    /// Each `A * xᵢ == eᵢ` is solved with one shared LU factorization, and only the `i`-th
+    /// component of `xᵢ` is kept.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrix is not square or cannot be inverted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     2, 0;
+    ///     0, 4;
+    /// ];
+    ///
+    /// assert!(m.trace_of_inverse().unwrap().epsilon_equals(&0.75));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::trace`]
+    /// * [`Matrix::lu_factor`]
+    pub fn trace_of_inverse(&self) -> Result<MatrixElement> {
+        let n = self.rows_number;
+        let lu = self.lu_factor()?;
+
+        let mut trace = MatrixElement::zero();
+        for i in 0..n {
+            let mut e = Vector::zero(n);
+            e.data[i] = MatrixElement::one();
+
+            trace += lu.solve(&e)?[i];
+        }
+
+        Ok(trace)
+    }
+
+    /// Returns the trace of `A⁻¹ * B` without computing the inverse of `A`.
+    ///
+    /// Each `A * xᵢ == bᵢ`, where `bᵢ` is the `i`-th column of `B`, is solved with one shared LU
+    /// factorization, and only the `i`-th component of `xᵢ` is kept.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the matrices are not square or have different sizes, or if `A` cannot be
+    /// inverted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let a = matrix![
+    ///     2, 0;
+    ///     0, 4;
+    /// ];
+    /// let b = matrix![
+    ///     6, 1;
+    ///     1, 8;
+    /// ];
+    ///
+    /// assert!(a.trace_of_inverse_times(&b).unwrap().epsilon_equals(&5));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::trace_of_inverse`]
+    /// * [`Matrix::lu_factor`]
+    pub fn trace_of_inverse_times(&self, other: &Self) -> Result<MatrixElement> {
+        let n = self.rows_number;
+
+        if other.rows_number != n || other.cols_number != n {
+            return Err(Error::InvalidOperation(
+                "Matrix dimensions must match to compute the trace of A⁻¹ * B",
+            ));
+        }
+
+        let lu = self.lu_factor()?;
+
+        let mut trace = MatrixElement::zero();
+        for (i, col) in other.as_cols().iter().enumerate() {
+            trace += lu.solve(col)?[i];
+        }
+
+        Ok(trace)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn trace() {
+        assert!(Matrix::identity(5).trace().unwrap().epsilon_equals(&5));
+        matrix![1, 2, 3; 4, 5, 6].trace().unwrap_err();
+    }
+
+    #[test]
+    fn trace_of_inverse() {
+        let m = matrix![
+            4, -2, 1;
+            3, 6, -4;
+            2, 1, 8;
+        ];
+
+        assert!(m
+            .trace_of_inverse()
+            .unwrap()
+            .epsilon_equals(&m.inverse().unwrap().trace().unwrap()));
+
+        matrix![1, 2; 2, 4].trace_of_inverse().unwrap_err();
+    }
+
+    #[test]
+    fn trace_of_inverse_times() {
+        let a = matrix![
+            4, -2, 1;
+            3, 6, -4;
+            2, 1, 8;
+        ];
+        let b = matrix![
+            1, 0, 2;
+            -1, 3, 0;
+            5, 1, -2;
+        ];
+
+        assert!(a
+            .trace_of_inverse_times(&b)
+            .unwrap()
+            .epsilon_equals(&(a.inverse().unwrap() * b.clone()).trace().unwrap()));
+
+        a.trace_of_inverse_times(&matrix![1, 2; 3, 4]).unwrap_err();
+        matrix![1, 2, 0; 2, 4, 0; 0, 0, 1]
+            .trace_of_inverse_times(&b)
+            .unwrap_err();
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{Matrix, Vector};
+
+impl Matrix {
+    /// Returns a copy of the matrix without duplicate rows, keeping the first occurrence of each
+    /// row and the original order.
+    ///
+    /// Rows are compared with [`Vector::epsilon_equals`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 2;
+    ///     3, 4;
+    ///     1, 2;
+    /// ];
+    ///
+    /// assert!(m.unique_rows().epsilon_equals(&matrix![
+    ///     1, 2;
+    ///     3, 4;
+    /// ]));
+    /// ```
+    pub fn unique_rows(&self) -> Self {
+        let mut rows: Vec<Vector> = Vec::with_capacity(self.rows_number);
+
+        for row in self.as_rows() {
+            if !rows.iter().any(|kept| kept.epsilon_equals(&row)) {
+                rows.push(row);
+            }
+        }
+
+        rows.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, Matrix, MatrixElement};
+
+    #[test]
+    fn unique_rows() {
+        let m = matrix![
+            5, 6, 7;
+            1, 2, 3;
+            1.00000000001, 2, 2.99999999999;
+            5, 6, 8;
+        ];
+
+        let unique = m.unique_rows();
+
+        assert_eq!(unique.rows_number, 3);
+        assert!(unique.epsilon_equals(&matrix![
+            5, 6, 7;
+            1, 2, 3;
+            5, 6, 8;
+        ]));
+    }
+}
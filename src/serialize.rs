@@ -0,0 +1,164 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Error, Matrix, MatrixElement, Result as MatrixResult};
+
+/// A matrix is serialized as a list of rows.
+impl Serialize for Matrix {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.as_rows())
+    }
+}
+
+impl<'de> Deserialize<'de> for Matrix {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let elements = Vec::<Vec<MatrixElement>>::deserialize(deserializer)?;
+
+        Matrix::try_new(elements).map_err(de::Error::custom)
+    }
+}
+
+/// The self-describing format of [`Matrix::to_json_compact`].
+#[derive(Serialize, Deserialize)]
+struct CompactMatrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<Vec<MatrixElement>>,
+}
+
+impl Matrix {
+    /// Serializes the matrix to a compact JSON object with its dimensions,
+    /// `{"rows":m,"cols":n,"data":[[...]]}`.
+    ///
+    /// Requires the `serde` feature.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if any element is infinite or `NaN`, since JSON has no numbers for them and
+    /// they would be written as `null`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = matrix![
+    ///     1, 2;
+    ///     3, 4.5;
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     m.to_json_compact().unwrap(),
+    ///     r#"{"rows":2,"cols":2,"data":[[1.0,2.0],[3.0,4.5]]}"#
+    /// );
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::from_json_compact`]
+    pub fn to_json_compact(&self) -> MatrixResult<String> {
+        if self.elements.iter().any(|x| !x.value().is_finite()) {
+            return Err(Error::InvalidOperation(
+                "Only matrices with finite elements can be written as JSON",
+            ));
+        }
+
+        let compact = CompactMatrix {
+            rows: self.rows_number,
+            cols: self.cols_number,
+            data: self.as_rows().into_iter().map(|row| row.data).collect(),
+        };
+
+        // INFO: safe to unwrap, the struct only holds finite numbers
+        Ok(serde_json::to_string(&compact).unwrap())
+    }
+
+    /// Parses a matrix from the compact JSON format of [`Matrix::to_json_compact`].
+    ///
+    /// Requires the `serde` feature.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the string is not valid JSON in the expected format, or if the
+    /// dimensions do not match the data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, Matrix, MatrixElement};
+    /// let m = Matrix::from_json_compact(r#"{"rows":1,"cols":2,"data":[[1,2]]}"#).unwrap();
+    ///
+    /// assert!(m.epsilon_equals(&matrix![1, 2]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::to_json_compact`]
+    pub fn from_json_compact(s: &str) -> MatrixResult<Self> {
+        let compact: CompactMatrix = serde_json::from_str(s)
+            .map_err(|_| Error::InvalidOperation("Invalid compact JSON matrix"))?;
+
+        let matrix = Matrix::try_new(compact.data)?;
+
+        if matrix.rows_number != compact.rows || matrix.cols_number != compact.cols {
+            return Err(Error::InvalidOperation(
+                "Matrix dimensions do not match the data",
+            ));
+        }
+
+        Ok(matrix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix, vector, Matrix, MatrixElement, Vector};
+
+    #[test]
+    fn round_trip() {
+        let m = matrix![
+            1, -2.5, 3;
+            4, 0.1, -6;
+        ];
+
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(json, "[[1.0,-2.5,3.0],[4.0,0.1,-6.0]]");
+        assert_eq!(serde_json::from_str::<Matrix>(&json).unwrap(), m);
+
+        let v = vector![1, 2.5, -3];
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, "[1.0,2.5,-3.0]");
+        assert_eq!(serde_json::from_str::<Vector>(&json).unwrap(), v);
+
+        let e = MatrixElement::new(-0.75);
+        let json = serde_json::to_string(&e).unwrap();
+        assert_eq!(json, "-0.75");
+        assert_eq!(serde_json::from_str::<MatrixElement>(&json).unwrap(), e);
+    }
+
+    #[test]
+    fn json_compact() {
+        let m = matrix![
+            1, -2.5, 3;
+            4, 0.1, -6;
+        ];
+
+        let json = m.to_json_compact().unwrap();
+        assert_eq!(
+            json,
+            r#"{"rows":2,"cols":3,"data":[[1.0,-2.5,3.0],[4.0,0.1,-6.0]]}"#
+        );
+        assert_eq!(Matrix::from_json_compact(&json).unwrap(), m);
+
+        Matrix::from_json_compact(r#"{"rows":3,"cols":3,"data":[[1,2,3],[4,5,6]]}"#).unwrap_err();
+        Matrix::from_json_compact(r#"{"rows":2,"cols":2,"data":[[1,2],[3]]}"#).unwrap_err();
+        Matrix::from_json_compact("[[1, 2]]").unwrap_err();
+
+        matrix![1, f64::NAN].to_json_compact().unwrap_err();
+        matrix![f64::INFINITY, 1].to_json_compact().unwrap_err();
+    }
+
+    #[test]
+    fn deserialize_invalid() {
+        serde_json::from_str::<Matrix>("[]").unwrap_err();
+        serde_json::from_str::<Matrix>("[[1, 2], [3]]").unwrap_err();
+    }
+}
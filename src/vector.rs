@@ -1,9 +1,18 @@
-use std::{ops::Index, vec::IntoIter};
+use std::{
+    cmp::Reverse,
+    ops::{Index, IndexMut},
+    vec::IntoIter,
+};
 
-use crate::MatrixElement;
+use crate::{Error, Matrix, MatrixElement, Result, TotalOrderElement};
 
 /// A vector.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Vector {
     /// The raw data of the vector.
     pub data: Vec<MatrixElement>,
@@ -80,6 +89,12 @@ impl Index<usize> for Vector {
     }
 }
 
+impl IndexMut<usize> for Vector {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
 impl Vector {
     /// Returns a vector with length `len` with all elements set to `0`.
     pub fn zero(len: usize) -> Self {
@@ -134,6 +149,22 @@ impl Vector {
             .collect()
     }
 
+    /// Returns a new vector with `f` applied to each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{vector, Vector, MatrixElement};
+    /// assert!(vector![1, -2, 3].map(|x| x * x).epsilon_equals(&vector![1, 4, 9]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Matrix::map`]
+    pub fn map<F: Fn(MatrixElement) -> MatrixElement>(&self, f: F) -> Self {
+        self.data.iter().map(|&element| f(element)).collect()
+    }
+
     /// Adds two vectors.
     ///
     /// # Panics
@@ -200,6 +231,37 @@ impl Vector {
             .collect()
     }
 
+    /// Linearly interpolates between two vectors, returning `(1 - t) * self + t * other`.
+    ///
+    /// `t` is not clamped, so values outside `[0, 1]` extrapolate along the line through both
+    /// vectors.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the vectors have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{vector, Vector, MatrixElement};
+    /// let midpoint = vector![0, 2].lerp(&vector![4, -2], 0.5).unwrap();
+    ///
+    /// assert!(midpoint.epsilon_equals(&vector![2, 0]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Linear interpolation](https://en.wikipedia.org/wiki/Linear_interpolation)
+    pub fn lerp(&self, other: &Self, t: f64) -> Result<Self> {
+        if self.len() != other.len() {
+            return Err(Error::InvalidOperation(
+                "Vector length must be equal to interpolate",
+            ));
+        }
+
+        Ok(self.scale(1.0 - t).add(&other.scale(t)))
+    }
+
     /// Returns the dot product of two vectors.
     ///
     /// # Examples
@@ -217,6 +279,474 @@ impl Vector {
             .fold(MatrixElement::zero(), |acc, x| acc + x)
     }
 
+    /// Returns the Hermitian inner product `conj(self) · other`.
+    ///
+    /// The first argument is conjugated. Since the elements are real, this is the same as
+    /// [`Vector::dot`], but it states the intent for code that should also work with complex data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{vector, Vector, MatrixElement};
+    /// assert!(vector![1, 2, -3].dot_conjugate(&vector![4, 5, 6]).epsilon_equals(&-4));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Inner product space](https://en.wikipedia.org/wiki/Inner_product_space)
+    /// * [`Vector::dot`]
+    pub fn dot_conjugate(&self, other: &Self) -> MatrixElement {
+        // conjugation is the identity on real numbers
+        self.dot(other)
+    }
+
+    /// Returns the Euclidean norm of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{vector, Vector, MatrixElement};
+    /// assert!(vector![3, -4].norm().epsilon_equals(&5));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Euclidean norm](https://en.wikipedia.org/wiki/Norm_(mathematics)#Euclidean_norm)
+    pub fn norm(&self) -> MatrixElement {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns the Lp norm of the vector, which is `(Σ|xᵢ|^p)^(1/p)`.
+    ///
+    /// NOTE: The result is only a norm for `p >= 1`. Smaller values of `p` are computed with the
+    ///       same formula, which does not satisfy the triangle inequality.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{vector, Vector, MatrixElement};
+    /// assert!(vector![3, -4].lp_norm(1.0).epsilon_equals(&7));
+    /// assert!(vector![3, -4].lp_norm(2.0).epsilon_equals(&5));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [p-norm](https://en.wikipedia.org/wiki/Norm_(mathematics)#p-norm)
+    /// * [`Vector::norm`]
+    /// * [`Vector::inf_norm`]
+    pub fn lp_norm(&self, p: f64) -> MatrixElement {
+        self.data
+            .iter()
+            .fold(MatrixElement::zero(), |acc, x| acc + x.abs().powf(p))
+            .powf(p.recip())
+    }
+
+    /// Returns the infinity norm of the vector, which is the largest absolute value of its
+    /// components.
+    ///
+    /// The infinity norm of an empty vector is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{vector, Vector, MatrixElement};
+    /// assert!(vector![3, -4].inf_norm().epsilon_equals(&4));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Maximum norm](https://en.wikipedia.org/wiki/Norm_(mathematics)#Maximum_norm_(special_case_of:_infinity_norm,_uniform_norm,_or_supremum_norm))
+    /// * [`Vector::lp_norm`]
+    pub fn inf_norm(&self) -> MatrixElement {
+        self.data.iter().map(|x| x.abs()).fold(
+            MatrixElement::zero(),
+            |acc, x| if x > acc { x } else { acc },
+        )
+    }
+
+    /// Returns the sum of the components of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{vector, Vector, MatrixElement};
+    /// assert!(vector![1, 2, 3].sum().epsilon_equals(&6));
+    /// ```
+    pub fn sum(&self) -> MatrixElement {
+        self.data
+            .iter()
+            .fold(MatrixElement::zero(), |acc, &x| acc + x)
+    }
+
+    /// Returns the arithmetic mean of the components of the vector.
+    ///
+    /// The mean of an empty vector is `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{vector, Vector, MatrixElement};
+    /// assert!(vector![1, 2, 3].mean().epsilon_equals(&2));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Vector::sum`]
+    pub fn mean(&self) -> MatrixElement {
+        self.sum() / self.len() as f64
+    }
+
+    /// Returns the largest component of the vector, compared with
+    /// [`MatrixElement::epsilon_cmp`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{vector, Vector, MatrixElement};
+    /// assert!(vector![1, 2, 3].max().epsilon_equals(&3));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Vector::min`]
+    pub fn max(&self) -> MatrixElement {
+        if self.is_empty() {
+            panic!("Cannot find the maximum of an empty vector");
+        }
+
+        // INFO: safe to unwrap, since the vector is not empty
+        *self.data.iter().max_by(|a, b| a.epsilon_cmp(b)).unwrap()
+    }
+
+    /// Returns the smallest component of the vector, compared with
+    /// [`MatrixElement::epsilon_cmp`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{vector, Vector, MatrixElement};
+    /// assert!(vector![1, 2, 3].min().epsilon_equals(&1));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Vector::max`]
+    pub fn min(&self) -> MatrixElement {
+        if self.is_empty() {
+            panic!("Cannot find the minimum of an empty vector");
+        }
+
+        // INFO: safe to unwrap, since the vector is not empty
+        *self.data.iter().min_by(|a, b| a.epsilon_cmp(b)).unwrap()
+    }
+
+    /// Returns the Euclidean distance between two vectors, which is the norm of their difference.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the vectors have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{vector, Vector, MatrixElement};
+    /// assert!(vector![0, 0].distance(&vector![3, 4]).unwrap().epsilon_equals(&5));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Euclidean distance](https://en.wikipedia.org/wiki/Euclidean_distance)
+    /// * [`Vector::norm`]
+    pub fn distance(&self, other: &Self) -> Result<MatrixElement> {
+        if self.len() != other.len() {
+            return Err(Error::InvalidOperation(
+                "Vector length must be equal to compute the distance",
+            ));
+        }
+
+        Ok(self.subtract(other).norm())
+    }
+
+    /// Returns the outer product of two vectors, which is the matrix `self * otherᵀ`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, Vector, MatrixElement};
+    /// assert!(vector![1, 2].outer(&vector![3, 4, 5]).epsilon_equals(&matrix![
+    ///     3, 4, 5;
+    ///     6, 8, 10;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Outer product](https://en.wikipedia.org/wiki/Outer_product)
+    pub fn outer(&self, other: &Self) -> Matrix {
+        self.data
+            .clone()
+            .into_iter()
+            .map(|element| other.scale(element))
+            .collect()
+    }
+
+    /// Returns the Householder vector `v` and the scalar `beta` such that `(I - beta * v vᵀ) x` is
+    /// a multiple of the first unit vector `e₁`, where `x` is this vector.
+    ///
+    /// The sign of `v[0]` is chosen to match `x[0]`, which avoids cancellation, and `v` is scaled
+    /// so that its size does not depend on the size of `x`. Since `beta == 2 / (vᵀ v)`, the
+    /// reflection is the Householder matrix of `v`.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the vector is empty, or if all of its elements are exactly zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{vector, Matrix, MatrixElement, Vector};
+    /// let x = vector![3, 4];
+    /// let (v, beta) = x.householder_vector().unwrap();
+    ///
+    /// let h = Matrix::householder(&v);
+    ///
+    /// assert!(h.multiply_vector(&x).unwrap().epsilon_equals(&vector![-5, 0]));
+    /// assert!(h.epsilon_equals(&(Matrix::identity(2) + v.outer(&v).scale(beta.negate()))));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Householder transformation](https://en.wikipedia.org/wiki/Householder_transformation#QR_decomposition)
+    /// * [`Matrix::householder`]
+    pub fn householder_vector(&self) -> Result<(Vector, MatrixElement)> {
+        let largest = self.inf_norm().value();
+        if self.is_empty() || largest == 0.0 {
+            return Err(Error::InvalidOperation(
+                "Cannot build a Householder vector from the zero vector",
+            ));
+        }
+
+        // scaling does not change the reflection, and keeps `vᵀ v` away from the epsilon of
+        // `MatrixElement` for tiny vectors
+        let mut v = self.scale(MatrixElement::new(1.0 / largest));
+
+        let norm = v.norm();
+        let alpha = if v.data[0].is_negative() {
+            norm.negate()
+        } else {
+            norm
+        };
+        v.data[0] += alpha;
+
+        let beta = MatrixElement::from(2).checked_div(v.dot(&v))?;
+
+        Ok((v, beta))
+    }
+
+    /// Returns the matrix `v vᵀ / (vᵀ v)` which orthogonally projects vectors onto the line
+    /// spanned by this vector.
+    ///
+    /// The result is symmetric and idempotent.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the vector is empty, or if all of its elements are exactly zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, MatrixElement, Vector};
+    /// let p = vector![1, 1].projection_matrix().unwrap();
+    ///
+    /// assert!(p.epsilon_equals(&matrix![
+    ///     0.5, 0.5;
+    ///     0.5, 0.5;
+    /// ]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * Wikipedia: [Projection (linear algebra)](https://en.wikipedia.org/wiki/Projection_(linear_algebra)#Orthogonal_projections)
+    /// * [`Vector::outer`]
+    pub fn projection_matrix(&self) -> Result<Matrix> {
+        let largest = self.inf_norm().value();
+        if self.is_empty() || largest == 0.0 {
+            return Err(Error::InvalidOperation(
+                "Cannot project onto the line spanned by the zero vector",
+            ));
+        }
+
+        // the squared norm of a tiny vector is below the epsilon of `MatrixElement`, so the vector
+        // is scaled to have a largest component of 1 first, which spans the same line
+        let u = self.scale(MatrixElement::new(1.0 / largest));
+
+        Ok(u.outer(&u).scale(u.dot(&u).inverse()))
+    }
+
+    /// Returns the vector as a matrix with a single column.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the vector is empty, since a matrix has at least one row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, Vector, MatrixElement};
+    /// assert!(vector![1, 2].as_column().unwrap().epsilon_equals(&matrix![1; 2]));
+    /// Vector::zero(0).as_column().unwrap_err();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Vector::as_row`]
+    /// * [`Matrix::to_vector`]
+    pub fn as_column(&self) -> Result<Matrix> {
+        if self.is_empty() {
+            return Err(Error::InvalidOperation("Matrix must have at least one row"));
+        }
+
+        Ok(Matrix::from_cols(vec![self.clone()]))
+    }
+
+    /// Returns the vector as a matrix with a single row.
+    ///
+    /// An empty vector gives a `1 × 0` matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, Vector, MatrixElement};
+    /// assert!(vector![1, 2].as_row().epsilon_equals(&matrix![1, 2]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Vector::as_column`]
+    /// * [`Matrix::to_vector`]
+    pub fn as_row(&self) -> Matrix {
+        vec![self.clone()].into_iter().collect()
+    }
+
+    /// Reshapes the vector into a matrix with the given size, filling it row by row.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if `rows * cols` is different from the length of the vector, or if the size
+    /// is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{matrix, vector, Matrix, Vector, MatrixElement};
+    /// assert!(vector![1, 2, 3, 4].into_matrix(2, 2).unwrap().epsilon_equals(&matrix![
+    ///     1, 2;
+    ///     3, 4;
+    /// ]));
+    /// ```
+    pub fn into_matrix(self, rows: usize, cols: usize) -> Result<Matrix> {
+        if rows == 0 || cols == 0 {
+            return Err(Error::InvalidOperation(
+                "Matrix must have at least one row and one column",
+            ));
+        }
+
+        if rows.checked_mul(cols) != Some(self.len()) {
+            return Err(Error::InvalidOperation(
+                "Vector length must be equal to rows * cols to reshape",
+            ));
+        }
+
+        Ok(self.data.chunks(cols).map(|row| row.to_vec()).collect())
+    }
+
+    /// Sorts the vector in ascending order, in place.
+    ///
+    /// The elements are compared with [`TotalOrderElement`], so `NaN` values are sorted
+    /// deterministically.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{vector, Vector, MatrixElement};
+    /// let mut vector = vector![3, 1, 2];
+    /// vector.sort();
+    ///
+    /// assert!(vector.epsilon_equals(&vector![1, 2, 3]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Vector::sorted`]
+    /// * [`Vector::sort_descending`]
+    pub fn sort(&mut self) {
+        self.data.sort_by_key(|&element| TotalOrderElement(element));
+    }
+
+    /// Sorts the vector in descending order, in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{vector, Vector, MatrixElement};
+    /// let mut vector = vector![3, 1, 2];
+    /// vector.sort_descending();
+    ///
+    /// assert!(vector.epsilon_equals(&vector![3, 2, 1]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Vector::sort`]
+    pub fn sort_descending(&mut self) {
+        self.data
+            .sort_by_key(|&element| Reverse(TotalOrderElement(element)));
+    }
+
+    /// Returns a copy of the vector sorted in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{vector, Vector, MatrixElement};
+    /// assert!(vector![3, 1, 2].sorted().epsilon_equals(&vector![1, 2, 3]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Vector::sort`]
+    pub fn sorted(&self) -> Self {
+        let mut vector = self.clone();
+        vector.sort();
+        vector
+    }
+
+    /// Returns a copy of the vector sorted in descending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_matrix::{vector, Vector, MatrixElement};
+    /// assert!(vector![3, 1, 2].sorted_descending().epsilon_equals(&vector![3, 2, 1]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Vector::sort_descending`]
+    pub fn sorted_descending(&self) -> Self {
+        let mut vector = self.clone();
+        vector.sort_descending();
+        vector
+    }
+
     /// Checks if the vector is equal to another vector within a certain epsilon.
     ///
     /// # Examples
@@ -244,7 +774,7 @@ impl Vector {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{vector, Vector};
+    use crate::matrix;
 
     #[test]
     #[should_panic]
@@ -257,4 +787,155 @@ mod tests {
     fn subtract_diff_length() {
         let _ = vector![1, 2, -3].subtract(&vector![4, 5]);
     }
+
+    #[test]
+    fn dot_conjugate() {
+        let a = vector![0.5, -2, 3, 7];
+        let b = vector![4, 1.25, -6, 0];
+
+        assert!(a.dot_conjugate(&b).epsilon_equals(&a.dot(&b)));
+        assert!(a.dot_conjugate(&a).epsilon_equals(&a.norm().powi(2)));
+    }
+
+    #[test]
+    fn into_matrix() {
+        let m = vector![1, 2, 3, 4, 5, 6].into_matrix(2, 3).unwrap();
+
+        assert_eq!(m.rows_number, 2);
+        assert_eq!(m.cols_number, 3);
+        assert!(m.epsilon_equals(&matrix![
+            1, 2, 3;
+            4, 5, 6;
+        ]));
+
+        vector![1, 2, 3, 4, 5, 6].into_matrix(4, 2).unwrap_err();
+        Vector::zero(0).into_matrix(0, 3).unwrap_err();
+        // the product of the dimensions overflows to zero
+        Vector::zero(0)
+            .into_matrix(usize::MAX / 2 + 1, 2)
+            .unwrap_err();
+    }
+
+    #[test]
+    fn sort() {
+        let vector = vector![3, 1, 2];
+
+        assert!(vector.sorted().epsilon_equals(&vector![1, 2, 3]));
+        assert!(vector.sorted_descending().epsilon_equals(&vector![3, 2, 1]));
+        // the original is left untouched
+        assert!(vector.epsilon_equals(&vector![3, 1, 2]));
+
+        let mut vector = vector![0.5, -4, 2.25, -4, 7];
+        vector.sort();
+
+        assert!(vector.epsilon_equals(&vector![-4, -4, 0.5, 2.25, 7]));
+    }
+
+    #[test]
+    fn householder_vector() {
+        for x in [
+            vector![1, -2, 3, 0.5],
+            vector![-4, 1, 1],
+            vector![0, 0, 2],
+            vector![7],
+        ] {
+            let (v, beta) = x.householder_vector().unwrap();
+            let h = Matrix::householder(&v);
+            let reflected = h.multiply_vector(&x).unwrap();
+
+            assert!(
+                h.epsilon_equals(&(Matrix::identity(x.len()) + v.outer(&v).scale(beta.negate())))
+            );
+
+            assert!(reflected.data[0].abs().epsilon_equals(&x.norm()));
+            assert!(reflected.data[1..].iter().all(|element| element.is_zero()));
+        }
+
+        // the squared norm of a tiny vector is below the epsilon of `MatrixElement`
+        let x = vector![3e-5, 4e-5];
+        let (v, beta) = x.householder_vector().unwrap();
+        let h = Matrix::householder(&v);
+
+        assert!(h.epsilon_equals(&(Matrix::identity(2) + v.outer(&v).scale(beta.negate()))));
+        assert!(h
+            .multiply_vector(&x.scale(MatrixElement::new(1e5)))
+            .unwrap()
+            .epsilon_equals(&vector![-5, 0]));
+
+        Vector::zero(3).householder_vector().unwrap_err();
+        Vector::zero(0).householder_vector().unwrap_err();
+    }
+
+    #[test]
+    fn projection_matrix() {
+        let v = vector![1, -2, 2];
+        let p = v.projection_matrix().unwrap();
+
+        assert!(p.is_symmetric());
+        assert!((p.clone() * p.clone()).epsilon_equals(&p));
+
+        assert!(p.multiply_vector(&v).unwrap().epsilon_equals(&v));
+        assert!(p.multiply_vector(&vector![2, 1, 0]).unwrap().is_zero());
+
+        // a tiny vector spans the same line
+        assert!(vector![5e-5, 0]
+            .projection_matrix()
+            .unwrap()
+            .epsilon_equals(&matrix![
+                1, 0;
+                0, 0;
+            ]));
+
+        Vector::zero(3).projection_matrix().unwrap_err();
+    }
+
+    #[test]
+    #[should_panic]
+    fn max_empty() {
+        let _ = Vector::zero(0).max();
+    }
+
+    #[test]
+    fn index_mut() {
+        let mut v = vector![1, 2, 3];
+        v[0] = MatrixElement::new(-7.5);
+        v[2] += 1;
+
+        assert!(v.epsilon_equals(&vector![-7.5, 2, 4]));
+    }
+
+    #[test]
+    fn map() {
+        let v = vector![1, -2.5, 0, 4];
+
+        assert!(v.map(|x| x * 2).epsilon_equals(&vector![2, -5, 0, 8]));
+    }
+
+    #[test]
+    fn lerp() {
+        let a = vector![1, -2, 3];
+        let b = vector![5, 2, -1];
+
+        assert!(a.lerp(&b, 0.0).unwrap().epsilon_equals(&a));
+        assert!(a.lerp(&b, 1.0).unwrap().epsilon_equals(&b));
+        assert!(a.lerp(&b, 0.25).unwrap().epsilon_equals(&vector![2, -1, 2]));
+        assert!(a.lerp(&b, 2.0).unwrap().epsilon_equals(&vector![9, 6, -5]));
+
+        a.lerp(&vector![1, 2], 0.5).unwrap_err();
+    }
+
+    #[test]
+    fn distance() {
+        let a = vector![1, -2, 3];
+        let b = vector![-1, 2, -1];
+
+        assert!(a.distance(&b).unwrap().epsilon_equals(&6));
+        assert!(a
+            .distance(&b)
+            .unwrap()
+            .epsilon_equals(&b.distance(&a).unwrap()));
+        assert!(a.distance(&a).unwrap().is_zero());
+
+        a.distance(&vector![1, 2]).unwrap_err();
+    }
 }